@@ -0,0 +1,85 @@
+//! Compares the ways of getting a megabyte of zeroed memory.
+//!
+//! Run with `cargo bench --bench zero_init`.
+//!
+//! Regression guard: `alloc_zeroed_boxed` should stay on par with `vec![0u8; n]`, as both
+//! go through the allocator's zeroed allocation and may get pre-zeroed pages for free. The
+//! buffer path has to clear memory that is already mapped and is expected to be
+//! memset-bound. A boxed allocation that becomes much slower than the `vec!` baseline
+//! means the heap path has stopped using `alloc_zeroed`.
+
+use std::hint::black_box;
+
+use alloc_zeroed::{AllocZeroed, AllocZeroedBoxed, BoxedAllocator, alloc_zeroed_bytes};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+
+const SIZE: usize = 1 << 20;
+
+fn zero_init(c: &mut Criterion) {
+    let mut group = c.benchmark_group("zero_init_1mb");
+    group.throughput(Throughput::Bytes(SIZE as u64));
+
+    group.bench_function("alloc_zeroed_boxed", |b| {
+        b.iter(|| black_box(<[u8; SIZE]>::alloc_zeroed_boxed().unwrap()))
+    });
+
+    let mut buffer = vec![0xFFu8; SIZE];
+    group.bench_function("alloc_zeroed_slice", |b| {
+        b.iter(|| {
+            let slice = u8::alloc_zeroed_slice(black_box(&mut buffer)).unwrap();
+            black_box(slice.len())
+        })
+    });
+
+    group.bench_function("vec_baseline", |b| b.iter(|| black_box(vec![0u8; SIZE])));
+
+    group.finish();
+}
+
+fn small_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("zero_init_64b");
+    let mut buffer = [0xFFu8; 64];
+
+    group.bench_function("alloc_zeroed_bytes", |b| {
+        b.iter(|| {
+            let bytes = alloc_zeroed_bytes(black_box(&mut buffer), 64).unwrap();
+            black_box(bytes.len())
+        })
+    });
+
+    group.bench_function("u8_alloc_zeroed_slice", |b| {
+        b.iter(|| {
+            let (bytes, _) =
+                u8::alloc_zeroed_slice_with_remainder(black_box(&mut buffer), 64).unwrap();
+            black_box(bytes.len())
+        })
+    });
+
+    group.finish();
+}
+
+#[derive(AllocZeroed)]
+struct Node {
+    _key: u64,
+    _value: [u32; 6],
+}
+
+/// `BoxedAllocator` checks the layout once, so it should be at least as fast as repeated
+/// `alloc_zeroed_boxed` calls, which check it on every allocation.
+fn boxed_allocator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("boxed_node");
+
+    group.bench_function("alloc_zeroed_boxed", |b| {
+        b.iter(|| black_box(Node::alloc_zeroed_boxed().unwrap()))
+    });
+
+    let allocator = BoxedAllocator::<Node>::new().unwrap();
+    group.bench_function("boxed_allocator", |b| {
+        b.iter(|| black_box(allocator.alloc().unwrap()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, zero_init, small_bytes, boxed_allocator);
+criterion_main!(benches);
@@ -0,0 +1,129 @@
+use crate::{AllocError, AllocZeroed};
+
+/// An inline byte buffer whose start is always aligned to `ALIGN`.
+///
+/// Types with an alignment of at most `ALIGN` are placed at the very start of the buffer
+/// without padding, so allocations into it never fail on alignment, wherever the buffer
+/// itself lives. `#[repr(align)]` only accepts literals, so `ALIGN` is limited to the powers
+/// of two from 1 to 64.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::{AlignedBuffer, AllocZeroed};
+///
+/// let mut buffer = AlignedBuffer::<64, 8>::new();
+/// assert_eq!(buffer.as_mut_bytes().as_ptr() as usize % 8, 0);
+///
+/// let value = u64::alloc_zeroed(buffer.as_mut_bytes()).unwrap();
+/// assert_eq!(*value, 0);
+/// ```
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct AlignedBuffer<const N: usize, const ALIGN: usize>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    _align: [<Alignment<ALIGN> as SupportedAlignment>::Marker; 0],
+    bytes: [u8; N],
+}
+
+/// Selects the marker type that gives [`AlignedBuffer`] an alignment of `ALIGN`.
+#[derive(Debug)]
+pub struct Alignment<const ALIGN: usize>;
+
+/// Implemented for every [`Alignment`] that [`AlignedBuffer`] supports.
+///
+/// Code generic over the alignment of a buffer repeats this bound:
+///
+/// ```
+/// use alloc_zeroed::{AlignedBuffer, Alignment, AllocZeroed, SupportedAlignment};
+///
+/// fn first_word<const N: usize, const A: usize>(buffer: &mut AlignedBuffer<N, A>) -> Option<&mut u32>
+/// where
+///     Alignment<A>: SupportedAlignment,
+/// {
+///     u32::alloc_zeroed(buffer.as_mut_bytes()).ok()
+/// }
+///
+/// let mut buffer = AlignedBuffer::<16, 4>::new();
+/// assert_eq!(first_word(&mut buffer), Some(&mut 0));
+/// ```
+pub trait SupportedAlignment {
+    /// A zero-sized type aligned to the alignment.
+    type Marker: Copy + core::fmt::Debug;
+}
+
+macro_rules! supported_alignments {
+    ($($align:literal => $marker:ident),* $(,)?) => {$(
+        #[doc(hidden)]
+        #[derive(Debug, Clone, Copy)]
+        #[repr(align($align))]
+        pub struct $marker;
+
+        impl SupportedAlignment for Alignment<$align> {
+            type Marker = $marker;
+        }
+    )*};
+}
+
+supported_alignments! {
+    1 => Align1,
+    2 => Align2,
+    4 => Align4,
+    8 => Align8,
+    16 => Align16,
+    32 => Align32,
+    64 => Align64,
+}
+
+impl<const N: usize, const ALIGN: usize> AlignedBuffer<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    /// Creates a zeroed buffer.
+    pub const fn new() -> Self {
+        Self {
+            _align: [],
+            bytes: [0; N],
+        }
+    }
+
+    /// Borrows the buffer as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Borrows the buffer as mutable bytes, starting at an `ALIGN`-aligned address.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    /// Zero-initializes a `T` at the start of the buffer.
+    ///
+    /// `T` must not be aligned to more than `ALIGN`, which is checked at compile time, so
+    /// the allocation needs no padding and can only fail if `N` is smaller than `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::BufferTooSmall` if `T` does not fit in `N` bytes.
+    pub fn alloc<T: AllocZeroed>(&mut self) -> Result<&mut T, AllocError> {
+        const {
+            assert!(
+                align_of::<T>() <= ALIGN,
+                "the type is aligned to more than the AlignedBuffer"
+            )
+        };
+
+        T::alloc_zeroed(&mut self.bytes)
+    }
+}
+
+impl<const N: usize, const ALIGN: usize> Default for AlignedBuffer<N, ALIGN>
+where
+    Alignment<ALIGN>: SupportedAlignment,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{AllocError, AllocStrategy, AllocZeroed};
+
+use super::{align_padding, slice_layout, zeroed_slice_at};
+
+/// A cursor that carves successive zero-initialized allocations out of a buffer.
+///
+/// Each allocation starts at the next suitably aligned address after the previous one.
+/// A failed allocation leaves the cursor where it was.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::BufferAllocator;
+///
+/// let mut buffer = [0xFFu8; 256];
+/// let mut allocator = BufferAllocator::new(&mut buffer);
+///
+/// let header = allocator.alloc::<u32>().unwrap();
+/// let payload = allocator.alloc_slice::<u64>(4).unwrap();
+///
+/// *header = payload.len() as u32;
+/// assert_eq!(payload, [0; 4]);
+/// ```
+#[derive(Debug)]
+pub struct BufferAllocator<'a> {
+    mem: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> BufferAllocator<'a> {
+    pub fn new(mem: &'a mut [u8]) -> Self {
+        Self { mem, position: 0 }
+    }
+
+    /// Returns the number of bytes consumed so far, including alignment padding.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.mem.len()
+    }
+
+    /// Returns the offset from the start of the buffer at which the next `T` would be placed.
+    ///
+    /// This is the current position plus the alignment padding [`alloc`] would skip, and is
+    /// useful for checking an expected layout. Nothing is allocated, and the result does not
+    /// tell whether the `T` would fit. Zero-sized types take no padding.
+    ///
+    /// [`alloc`]: BufferAllocator::alloc
+    pub fn next_offset<T>(&self) -> usize {
+        if size_of::<T>() == 0 {
+            return self.position;
+        }
+
+        let padding = align_padding(self.mem.as_ptr(), align_of::<T>());
+
+        self.position.saturating_add(padding)
+    }
+
+    /// Allocates a zero-initialized `T`.
+    pub fn alloc<T: AllocZeroed>(&mut self) -> Result<&'a mut T, AllocError> {
+        let slice = self.alloc_slice::<T>(1)?;
+
+        Ok(&mut slice[0])
+    }
+
+    /// Allocates a slice of `count` zero-initialized `T` values.
+    pub fn alloc_slice<T: AllocZeroed>(&mut self, count: usize) -> Result<&'a mut [T], AllocError> {
+        let region = self.take_region::<T>(count)?;
+
+        // SAFETY: `take_region` aligned `region` for `T` and sized it for `count` values
+        let (slice, _) = unsafe { zeroed_slice_at::<T>(region, 0, count) };
+
+        Ok(slice)
+    }
+
+    /// Advances past `count` values of `T` and returns their bytes, aligned for `T` but not
+    /// zeroed. A failure leaves the cursor where it was.
+    pub(crate) fn take_region<T>(&mut self, count: usize) -> Result<&'a mut [u8], AllocError> {
+        let (offset, total_bytes) = slice_layout::<T>(self.mem, count)?;
+
+        let mem = core::mem::take(&mut self.mem);
+        let (region, remainder) = mem[offset..].split_at_mut(total_bytes);
+
+        self.position += offset + total_bytes;
+        self.mem = remainder;
+
+        Ok(region)
+    }
+
+    /// Starts a transaction whose allocations only advance this allocator once committed.
+    ///
+    /// See [`TxAllocator`].
+    pub fn transaction(&mut self) -> TxAllocator<'_, 'a> {
+        TxAllocator::new(self)
+    }
+}
+
+impl<'a> AllocStrategy<'a> for BufferAllocator<'a> {
+    fn allocate_zeroed<T: AllocZeroed + 'a>(&mut self) -> Result<&'a mut T, AllocError> {
+        self.alloc()
+    }
+}
+
+/// A transaction over a [`BufferAllocator`], for allocation sequences where a later step
+/// may fail.
+///
+/// Allocations are carved from the parent's remaining buffer as usual, but the parent only
+/// advances past them when [`commit`] is called. Dropping the transaction without
+/// committing rolls it back: the parent stays where it was, and the space becomes
+/// available again once the transaction's allocations are no longer used. The zeroed
+/// bytes remain in the buffer.
+///
+/// Allocations borrow the parent for the lifetime of the transaction, so they cannot
+/// outlive it even when committed.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::BufferAllocator;
+///
+/// let mut buffer = [0xFFu8; 64];
+/// let mut allocator = BufferAllocator::new(&mut buffer);
+///
+/// let mut tx = allocator.transaction();
+/// let header = tx.alloc::<u32>().unwrap();
+/// *header = 7;
+/// assert!(tx.alloc_slice::<u64>(100).is_err());
+/// drop(tx);
+/// assert_eq!(allocator.position(), 0);
+///
+/// let mut tx = allocator.transaction();
+/// tx.alloc::<u32>().unwrap();
+/// tx.commit();
+/// assert_eq!(allocator.position(), 4);
+/// ```
+///
+/// [`commit`]: TxAllocator::commit
+#[derive(Debug)]
+pub struct TxAllocator<'t, 'a> {
+    parent: &'t mut BufferAllocator<'a>,
+    cursor: BufferAllocator<'t>,
+}
+
+impl<'t, 'a> TxAllocator<'t, 'a> {
+    /// Starts a transaction at the current position of `parent`.
+    pub fn new(parent: &'t mut BufferAllocator<'a>) -> Self {
+        // The transaction's cursor is derived from a pointer rather than reborrowed, which
+        // leaves `parent.mem` untouched so that a rollback needs no restoring
+        let base = parent.mem.as_mut_ptr();
+        let len = parent.mem.len();
+
+        // SAFETY: `parent` is borrowed exclusively for `'t` and does not access its buffer
+        // until then, so the transaction has sole use of the remaining bytes
+        let mem = unsafe { core::slice::from_raw_parts_mut(base, len) };
+
+        Self {
+            parent,
+            cursor: BufferAllocator::new(mem),
+        }
+    }
+
+    /// Returns the number of bytes consumed by the transaction so far, including alignment
+    /// padding.
+    pub fn position(&self) -> usize {
+        self.cursor.position()
+    }
+
+    /// Returns the number of bytes not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.cursor.remaining()
+    }
+
+    /// Allocates a zero-initialized `T`.
+    pub fn alloc<T: AllocZeroed>(&mut self) -> Result<&'t mut T, AllocError> {
+        self.cursor.alloc()
+    }
+
+    /// Allocates a slice of `count` zero-initialized `T` values.
+    pub fn alloc_slice<T: AllocZeroed>(&mut self, count: usize) -> Result<&'t mut [T], AllocError> {
+        self.cursor.alloc_slice(count)
+    }
+
+    /// Commits the transaction, advancing the parent past its allocations.
+    pub fn commit(mut self) {
+        let rest = core::mem::take(&mut self.cursor.mem);
+
+        // SAFETY: The bytes not handed out by the transaction belong to the parent's buffer,
+        // borrowed for `'a`, and are only reachable through `rest` from now on
+        self.parent.mem = unsafe { core::slice::from_raw_parts_mut(rest.as_mut_ptr(), rest.len()) };
+        self.parent.position += self.cursor.position;
+    }
+}
@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// A zero-initialized `T` in a buffer that can be accessed both as `T` and as raw bytes.
+///
+/// Returned by [`AllocZeroed::alloc_zeroed_dual`]. Both views are derived from the same raw
+/// pointer on every call, so switching between them is allowed, but only one may be used at
+/// a time: a reference obtained from one view must not be used after the other view was
+/// accessed. The view borrows the buffer exclusively for `'a`.
+///
+/// [`AllocZeroed::alloc_zeroed_dual`]: crate::AllocZeroed::alloc_zeroed_dual
+#[derive(Debug)]
+pub struct DualView<'a, T> {
+    ptr: NonNull<T>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> DualView<'a, T> {
+    /// Creates a view of the `T` at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid `T` whose bytes are all initialized, borrowed exclusively
+    /// for `'a`.
+    pub(crate) unsafe fn new(ptr: NonNull<T>) -> Self {
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the typed pointer.
+    ///
+    /// Dereferencing it is `unsafe`: the bytes must still form a valid `T`, and no
+    /// reference returned by [`bytes`](DualView::bytes) may be used afterwards.
+    pub fn as_ptr(&self) -> NonNull<T> {
+        self.ptr
+    }
+
+    /// Returns the bytes of the value.
+    ///
+    /// # Safety
+    ///
+    /// Every byte must be initialized. Writing a whole `T` through the typed pointer may
+    /// leave its padding bytes uninitialized, so only write individual fields (or types
+    /// without padding) before reading the bytes. No reference derived from the typed
+    /// pointer may be used while the returned slice is alive.
+    pub unsafe fn bytes(&mut self) -> &mut [u8] {
+        // SAFETY: The region is `size_of::<T>()` bytes borrowed exclusively for `'a`, and
+        // the caller guarantees they are initialized and not otherwise accessed
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr().cast(), size_of::<T>()) }
+    }
+}
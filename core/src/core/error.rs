@@ -3,10 +3,7 @@ use core::fmt;
 #[derive(Debug, Clone, Copy)]
 pub struct AllocError {
     kind: AllocErrorKind,
-    type_name: Option<&'static str>,
-    file: Option<&'static str>,
-    line: Option<u32>,
-    additional_context: Option<&'static str>,
+    metadata: Metadata,
 }
 
 impl AllocError {
@@ -19,18 +16,30 @@ impl AllocError {
     }
 
     pub fn type_name(&self) -> Option<&'static str> {
-        self.type_name
+        self.metadata.type_name()
     }
 
     pub fn location(&self) -> Option<(&'static str, u32)> {
-        self.file.zip(self.line)
+        self.metadata.location()
     }
 
+    /// Returns the first context attached to the error, if any.
     pub fn additional_context(&self) -> Option<&'static str> {
-        self.additional_context
+        self.contexts().next()
+    }
+
+    /// Returns every context attached to the error, in the order they were added.
+    pub fn contexts(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.metadata.contexts()
+    }
+
+    /// Returns how many attempts were made before giving up, if a retry loop recorded it.
+    pub fn attempts(&self) -> Option<u32> {
+        self.metadata.attempts()
     }
 
-    // Convenience methods for common error types
+    // Convenience methods for common error types. `buffer_too_small` reports no alignment
+    // padding; build the kind directly when padding reduced the available space.
     pub fn buffer_too_small(
         required: usize,
         available: usize,
@@ -40,6 +49,7 @@ impl AllocError {
             required,
             available,
             alignment,
+            padding: 0,
         })
     }
 
@@ -50,6 +60,58 @@ impl AllocError {
         })
     }
 
+    pub fn invalid_layout(size: usize, alignment: usize) -> AllocErrorBuilder {
+        AllocErrorBuilder::new(AllocErrorKind::InvalidLayout { size, alignment })
+    }
+
+    /// Starts an [`AllocErrorKind::Custom`] error, for crates built on this one that need to
+    /// report their own conditions (e.g. an exhausted pool) through `AllocError`.
+    pub fn custom(code: u32, message: &'static str) -> AllocErrorBuilder {
+        AllocErrorBuilder::new(AllocErrorKind::Custom { code, message })
+    }
+
+    /// Returns the code of an [`AllocErrorKind::Custom`] error, or `None` for the built-in
+    /// kinds.
+    pub fn code(&self) -> Option<u32> {
+        match self.kind {
+            AllocErrorKind::Custom { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// Returns the base message of the error, without the type name, location and context
+    /// that the `Display` implementation of `AllocError` appends.
+    ///
+    /// This suits messages shown to end users, while the full `Display` output is meant for
+    /// developer logs.
+    pub fn message(&self) -> impl fmt::Display {
+        self.kind
+    }
+
+    /// Formats the error message into `buf` without allocating, returning the written text.
+    ///
+    /// Fails with [`fmt::Error`] if `buf` is too small to hold the whole message.
+    pub fn write_message<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+        use fmt::Write;
+
+        let mut writer = SliceWriter { buf, len: 0 };
+        write!(writer, "{}", self)?;
+
+        let SliceWriter { buf, len } = writer;
+        core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+    }
+
+    /// Returns `true` if this error is the same variant as `other`, ignoring payloads.
+    pub fn same_kind(&self, other: &AllocErrorKind) -> bool {
+        self.kind.tag() == other.tag()
+    }
+
+    /// Returns `true` if both errors have the same kind and payload, ignoring metadata such
+    /// as the type name, location and context.
+    pub fn payload_eq(&self, other: &AllocError) -> bool {
+        self.kind == other.kind
+    }
+
     pub fn is_insufficient_memory(&self) -> bool {
         use AllocErrorKind::*;
 
@@ -73,6 +135,8 @@ pub enum AllocErrorKind {
         required: usize,
         available: usize,
         alignment: usize,
+        /// Bytes skipped to align the allocation, already subtracted from `available`.
+        padding: usize,
     },
     OutOfMemory {
         required: usize,
@@ -86,68 +150,134 @@ pub enum AllocErrorKind {
         size: usize,
         alignment: usize,
     },
+    DirtyBuffer {
+        offset: usize,
+    },
+    /// An error defined by a crate built on this one. The meaning of `code` is up to that
+    /// crate; `message` is what `Display` prints.
+    Custom {
+        code: u32,
+        message: &'static str,
+    },
+}
+
+impl AllocErrorKind {
+    pub fn tag(&self) -> AllocErrorTag {
+        match self {
+            AllocErrorKind::BufferTooSmall { .. } => AllocErrorTag::BufferTooSmall,
+            AllocErrorKind::OutOfMemory { .. } => AllocErrorTag::OutOfMemory,
+            AllocErrorKind::AlignmentFailed { .. } => AllocErrorTag::AlignmentFailed,
+            AllocErrorKind::InvalidLayout { .. } => AllocErrorTag::InvalidLayout,
+            AllocErrorKind::DirtyBuffer { .. } => AllocErrorTag::DirtyBuffer,
+            AllocErrorKind::Custom { .. } => AllocErrorTag::Custom,
+        }
+    }
+}
+
+/// The variant of an [`AllocErrorKind`] without its payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllocErrorTag {
+    BufferTooSmall,
+    OutOfMemory,
+    AlignmentFailed,
+    InvalidLayout,
+    DirtyBuffer,
+    Custom,
 }
 
+/// The error kinds the buffer-based `AllocZeroed` methods (`alloc_zeroed`,
+/// `alloc_zeroed_slice_with_remainder`, ...) can return.
+///
+/// `alloc_zeroed_verify_clean` can additionally return [`AllocErrorTag::DirtyBuffer`]. The
+/// `alloc_zeroed_chunks` function carves a buffer like these methods, and can additionally
+/// return [`AllocErrorTag::OutOfMemory`] when its list of chunks cannot be allocated.
+/// [`AllocErrorTag::AlignmentFailed`] is reserved for alignments that are not a power of
+/// two, which no type has, so the current methods never return it.
+pub const BUFFER_METHOD_ERRORS: &[AllocErrorTag] = &[
+    AllocErrorTag::BufferTooSmall,
+    AllocErrorTag::AlignmentFailed,
+    AllocErrorTag::InvalidLayout,
+];
+
+/// The error kinds the heap-based `AllocZeroedBoxed` methods (`alloc_zeroed_boxed`,
+/// `alloc_zeroed_boxed_slice`, ...) can return.
+pub const BOXED_METHOD_ERRORS: &[AllocErrorTag] =
+    &[AllocErrorTag::OutOfMemory, AllocErrorTag::InvalidLayout];
+
 #[derive(Debug, Clone, Copy)]
 pub struct AllocErrorBuilder {
     kind: AllocErrorKind,
-    type_name: Option<&'static str>,
-    file: Option<&'static str>,
-    line: Option<u32>,
-    additional_context: Option<&'static str>,
+    metadata: Metadata,
 }
 
 impl AllocErrorBuilder {
     pub fn new(kind: AllocErrorKind) -> Self {
         Self {
             kind,
-            type_name: None,
-            file: None,
-            line: None,
-            additional_context: None,
+            metadata: Metadata::new(),
         }
     }
 
     pub fn with_type_name(mut self, type_name: &'static str) -> Self {
-        self.type_name = Some(type_name);
+        self.metadata.set_type_name(type_name);
         self
     }
 
     pub fn with_location(mut self, file: &'static str, line: u32) -> Self {
-        self.file = Some(file);
-        self.line = Some(line);
+        self.metadata.set_location(file, line);
         self
     }
 
+    /// Attaches a context to the error. Contexts accumulate, so layers of code can each
+    /// add their own; up to [`MAX_CONTEXTS`] are kept and later ones are dropped.
     pub fn with_context(mut self, context: &'static str) -> Self {
-        self.additional_context = Some(context);
+        self.metadata.push_context(context);
+        self
+    }
+
+    /// Records how many attempts a retry loop made before the error was final.
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.metadata.set_attempts(attempts);
         self
     }
 
     pub fn build(self) -> AllocError {
-        AllocError {
+        let error = AllocError {
             kind: self.kind,
-            type_name: self.type_name,
-            file: self.file,
-            line: self.line,
-            additional_context: self.additional_context,
-        }
+            metadata: self.metadata,
+        };
+
+        // Report every finalized error when the `log` feature is enabled
+        #[cfg(feature = "log")]
+        log::warn!("allocation error: {}", error);
+
+        error
     }
 }
 
-impl fmt::Display for AllocError {
+impl fmt::Display for AllocErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Write the base error message
-        match self.kind {
+        match *self {
             AllocErrorKind::BufferTooSmall {
                 required,
                 available,
                 alignment,
+                padding: 0,
             } => write!(
                 f,
                 "required {} bytes (with {} alignment) but only {} bytes available",
                 required, alignment, available
             ),
+            AllocErrorKind::BufferTooSmall {
+                required,
+                available,
+                alignment,
+                padding,
+            } => write!(
+                f,
+                "required {} bytes (with {} alignment) but only {} bytes available after {} bytes of alignment padding",
+                required, alignment, available, padding
+            ),
             AllocErrorKind::OutOfMemory {
                 required,
                 alignment,
@@ -167,10 +297,21 @@ impl fmt::Display for AllocError {
             AllocErrorKind::InvalidLayout { size, alignment } => {
                 write!(f, "invalid layout: size={}, alignment={}", size, alignment)
             }
-        }?;
+            AllocErrorKind::DirtyBuffer { offset } => {
+                write!(f, "buffer is not clean: non-zero byte at offset {}", offset)
+            }
+            AllocErrorKind::Custom { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Write the base error message
+        write!(f, "{}", self.kind)?;
 
         // Add context information if available
-        if let Some(type_name) = self.type_name {
+        if let Some(type_name) = self.type_name() {
             write!(f, " (type: {})", type_name)?;
         }
 
@@ -178,10 +319,158 @@ impl fmt::Display for AllocError {
             write!(f, " (at {}:{})", file, line)?;
         }
 
-        if let Some(context) = self.additional_context {
-            write!(f, " (context: {})", context)?;
+        let mut contexts = self.contexts();
+        if let Some(first) = contexts.next() {
+            write!(f, " (context: {}", first)?;
+            for context in contexts {
+                write!(f, "; {}", context)?;
+            }
+            f.write_str(")")?;
+        }
+
+        if let Some(attempts) = self.attempts() {
+            write!(f, " (after {} attempts)", attempts)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The details attached to an error besides its kind.
+#[cfg(not(feature = "minimal-errors"))]
+#[derive(Debug, Clone, Copy)]
+struct Metadata {
+    type_name: Option<&'static str>,
+    file: Option<&'static str>,
+    line: Option<u32>,
+    contexts: Contexts,
+    attempts: Option<u32>,
+}
+
+#[cfg(not(feature = "minimal-errors"))]
+impl Metadata {
+    const fn new() -> Self {
+        Self {
+            type_name: None,
+            file: None,
+            line: None,
+            contexts: Contexts::new(),
+            attempts: None,
+        }
+    }
+
+    fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    fn location(&self) -> Option<(&'static str, u32)> {
+        self.file.zip(self.line)
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.contexts.iter()
+    }
+
+    fn attempts(&self) -> Option<u32> {
+        self.attempts
+    }
+
+    fn set_type_name(&mut self, type_name: &'static str) {
+        self.type_name = Some(type_name);
+    }
+
+    fn set_location(&mut self, file: &'static str, line: u32) {
+        self.file = Some(file);
+        self.line = Some(line);
+    }
+
+    fn push_context(&mut self, context: &'static str) {
+        self.contexts.push(context);
+    }
+
+    fn set_attempts(&mut self, attempts: u32) {
+        self.attempts = Some(attempts);
+    }
+}
+
+/// With the `minimal-errors` feature, errors carry no details besides their kind, so the
+/// setters are no-ops and the getters report nothing.
+#[cfg(feature = "minimal-errors")]
+#[derive(Debug, Clone, Copy)]
+struct Metadata;
+
+#[cfg(feature = "minimal-errors")]
+impl Metadata {
+    const fn new() -> Self {
+        Self
+    }
+
+    fn type_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn location(&self) -> Option<(&'static str, u32)> {
+        None
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &'static str> + '_ {
+        core::iter::empty()
+    }
+
+    fn attempts(&self) -> Option<u32> {
+        None
+    }
+
+    fn set_type_name(&mut self, _type_name: &'static str) {}
+
+    fn set_location(&mut self, _file: &'static str, _line: u32) {}
+
+    fn push_context(&mut self, _context: &'static str) {}
+
+    fn set_attempts(&mut self, _attempts: u32) {}
+}
+
+/// The number of contexts an [`AllocError`] keeps.
+///
+/// Errors are returned by value on every allocation path, so the list is kept short to keep
+/// `AllocError` small.
+pub const MAX_CONTEXTS: usize = 2;
+
+/// A fixed-capacity list of contexts, so that `AllocError` stays `Copy` and needs no
+/// allocator.
+#[cfg(not(feature = "minimal-errors"))]
+#[derive(Debug, Clone, Copy)]
+struct Contexts([Option<&'static str>; MAX_CONTEXTS]);
+
+#[cfg(not(feature = "minimal-errors"))]
+impl Contexts {
+    const fn new() -> Self {
+        Self([None; MAX_CONTEXTS])
+    }
+
+    fn push(&mut self, context: &'static str) {
+        if let Some(slot) = self.0.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(context);
         }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.iter().map_while(|slot| *slot)
+    }
+}
+
+/// A `fmt::Write` adapter over a fixed byte buffer.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
 
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        let dest = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len = end;
         Ok(())
     }
 }
@@ -0,0 +1,59 @@
+use crate::{AllocError, AllocZeroed};
+
+use super::slice_layout;
+
+/// Types for which every bit pattern is a valid value, so they can be initialized with any
+/// fill byte rather than only zero.
+///
+/// This is what formats with a non-zero blank state need, such as erased flash memory,
+/// which reads as `0xFF`.
+///
+/// # Safety
+///
+/// Implementors must ensure that any sequence of `size_of::<Self>()` initialized bytes is
+/// a valid `Self`. Types with padding, `bool`, `char`, enums, references and `NonZero`
+/// integers do not qualify.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::AllocFilled;
+///
+/// let mut buffer = [0u8; 64];
+/// let erased = <[u32; 4]>::alloc_filled(&mut buffer, 0xFF).unwrap();
+/// assert_eq!(*erased, [u32::MAX; 4]);
+/// ```
+pub unsafe trait AllocFilled: AllocZeroed {
+    /// Allocates an instance of `Self` in the buffer with every byte set to `byte`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`AllocZeroed::alloc_zeroed`].
+    fn alloc_filled(mem: &mut [u8], byte: u8) -> Result<&mut Self, AllocError> {
+        if size_of::<Self>() == 0 {
+            return Self::alloc_zeroed(mem);
+        }
+
+        let (offset, size) = slice_layout::<Self>(mem, 1)?;
+        let region = &mut mem[offset..offset + size];
+        region.fill(byte);
+
+        // SAFETY: `slice_layout` aligned `region` for `Self` and sized it to hold one, and
+        // every bit pattern is a valid `Self` per the trait contract
+        Ok(unsafe { &mut *region.as_mut_ptr().cast::<Self>() })
+    }
+}
+
+unsafe impl AllocFilled for u8 {}
+unsafe impl AllocFilled for u16 {}
+unsafe impl AllocFilled for u32 {}
+unsafe impl AllocFilled for u64 {}
+unsafe impl AllocFilled for usize {}
+unsafe impl AllocFilled for i8 {}
+unsafe impl AllocFilled for i16 {}
+unsafe impl AllocFilled for i32 {}
+unsafe impl AllocFilled for i64 {}
+unsafe impl AllocFilled for isize {}
+
+// SAFETY: Arrays have no padding between elements, so any bytes form valid elements
+unsafe impl<T: AllocFilled, const N: usize> AllocFilled for [T; N] {}
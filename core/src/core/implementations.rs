@@ -1,4 +1,24 @@
+//! Implementations of [`AllocZeroed`] for core types.
+//!
+//! Arrays and tuples (up to 16 elements) are zeroable whenever their elements are, so
+//! the impls compose through arbitrary nesting:
+//!
+//! ```
+//! use alloc_zeroed::AllocZeroed;
+//!
+//! let mut buffer = [0xFFu8; 64];
+//! let nested = <((u8, u16), (u32, u64))>::alloc_zeroed(&mut buffer).unwrap();
+//! assert_eq!(*nested, ((0, 0), (0, 0)));
+//! ```
+//!
+//! Each level of nesting adds one step to trait resolution, which the compiler bounds by
+//! its `recursion_limit` (128 by default), so types nested up to about 127 levels deep
+//! resolve out of the box; deeper ones need `#![recursion_limit = "256"]` in the crate using
+//! them. The width of a tuple does not count towards the limit.
+
+use core::marker::{PhantomData, PhantomPinned};
 use core::mem::MaybeUninit;
+use core::ptr::NonNull;
 
 use crate::AllocZeroed;
 
@@ -17,6 +37,25 @@ unsafe impl AllocZeroed for bool {}
 unsafe impl AllocZeroed for f32 {}
 unsafe impl AllocZeroed for f64 {}
 
+// SAFETY: Atomic integers have the same in-memory representation as their underlying
+// integer type, so all-zero is a valid value (an atomic holding 0 / false).
+macro_rules! impl_atomic {
+    ($($width:literal => $($T:ident),+;)+) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            unsafe impl AllocZeroed for core::sync::atomic::$T {}
+        )+)+
+    }
+}
+
+impl_atomic! {
+    "8" => AtomicBool, AtomicU8, AtomicI8;
+    "16" => AtomicU16, AtomicI16;
+    "32" => AtomicU32, AtomicI32;
+    "64" => AtomicU64, AtomicI64;
+    "ptr" => AtomicUsize, AtomicIsize;
+}
+
 // Implement for arrays of AllocZeroed types
 unsafe impl<T: AllocZeroed, const N: usize> AllocZeroed for [T; N] {}
 
@@ -27,16 +66,93 @@ macro_rules! impl_tuple {
     }
 }
 
-impl_tuple!(A);
-impl_tuple!(A, B);
-impl_tuple!(A, B, C);
-impl_tuple!(A, B, C, D);
-impl_tuple!(A, B, C, D, E);
-impl_tuple!(A, B, C, D, E, F);
-impl_tuple!(A, B, C, D, E, F, G);
-impl_tuple!(A, B, C, D, E, F, G, H);
+// Implement for every tuple length by growing the list of type parameters one at a time
+macro_rules! impl_tuples {
+    ([$($done:ident),+]) => {
+        impl_tuple!($($done),+);
+    };
+    ([$($done:ident),+], $next:ident $(, $rest:ident)*) => {
+        impl_tuple!($($done),+);
+        impl_tuples!([$($done,)+ $next] $(, $rest)*);
+    };
+}
+
+impl_tuples!([A], B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
 
 // SAFETY: MaybeUninit<T> can safely contain any bit pattern, including all zeros.
 // The default implementation of alloc_zeroed will zero the memory, which is always
 // safe for MaybeUninit<T> regardless of T.
 unsafe impl<T> AllocZeroed for MaybeUninit<T> {}
+
+// SAFETY: PhantomData<T> is a zero-sized type with no bytes to initialize, regardless of T.
+unsafe impl<T: ?Sized> AllocZeroed for PhantomData<T> {}
+
+// SAFETY: PhantomPinned is a zero-sized marker type with no bytes to initialize.
+unsafe impl AllocZeroed for PhantomPinned {}
+
+// SAFETY: Saturating<T> is #[repr(transparent)] over T, so it is zeroable whenever T is.
+unsafe impl<T: AllocZeroed> AllocZeroed for core::num::Saturating<T> {}
+
+// SAFETY: Option<NonNull<T>> has the same layout as a raw pointer, with `None` represented by
+// null. `NonNull<T>` itself is not zeroable, as null is never a valid `NonNull`.
+unsafe impl<T> AllocZeroed for Option<NonNull<T>> {}
+
+// SAFETY: `Option<NonZeroX>` is guaranteed to have the same size and layout as the integer
+// `X`, with `None` represented by zero (the niche the `NonZero` types reserve). A zeroed
+// `Option<NonZeroU32>` is therefore `None`, which makes it a compact optional count.
+macro_rules! impl_option_nonzero {
+    ($($T:ident),+) => {
+        $(unsafe impl AllocZeroed for Option<core::num::$T> {})+
+    }
+}
+
+impl_option_nonzero!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroIsize
+);
+
+// SAFETY: Function pointers are never null, so `Option<fn(..) -> R>` is guaranteed to be
+// represented like a pointer with `None` as null. A zeroed dispatch table of such options
+// holds no handlers. Function pointers with higher-ranked lifetimes, such as `fn(&T)`, are
+// distinct types that these impls do not cover.
+macro_rules! impl_option_fn {
+    ($($A:ident),*) => {
+        unsafe impl<R, $($A),*> AllocZeroed for Option<fn($($A),*) -> R> {}
+        unsafe impl<R, $($A),*> AllocZeroed for Option<unsafe fn($($A),*) -> R> {}
+        unsafe impl<R, $($A),*> AllocZeroed for Option<extern "C" fn($($A),*) -> R> {}
+        unsafe impl<R, $($A),*> AllocZeroed for Option<unsafe extern "C" fn($($A),*) -> R> {}
+    };
+}
+
+impl_option_fn!();
+impl_option_fn!(A);
+impl_option_fn!(A, B);
+impl_option_fn!(A, B, C);
+impl_option_fn!(A, B, C, D);
+impl_option_fn!(A, B, C, D, E);
+impl_option_fn!(A, B, C, D, E, F);
+
+// SAFETY: GenericArray<T, N> is #[repr(transparent)] over an array of N elements of T, so it
+// is zeroable whenever T is.
+#[cfg(feature = "generic-array")]
+unsafe impl<T: AllocZeroed, N: generic_array::ArrayLength> AllocZeroed
+    for generic_array::GenericArray<T, N>
+{
+}
+
+// Not implemented on purpose:
+// * `NonNull<T>`, references and `Box<T>` can never be null, nor can the `NonZero` integers
+//   outside of an `Option`.
+// * `core::ops::ControlFlow` and other enums from core: the layout of an enum without a
+//   primitive `repr` is unspecified, so no variant is guaranteed to be represented by zeros.
+// * `core::sync::atomic::Ordering`, for the same reason. It is unrelated to `cmp::Ordering`,
+//   whose `Equal` variant is zero.
+// The derive macro reports fields of these types with a dedicated message.
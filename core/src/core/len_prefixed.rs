@@ -0,0 +1,42 @@
+use core::marker::PhantomData;
+
+use crate::{AllocError, AllocZeroed};
+
+/// Allocates `{ header; items[count] }` records, the shape of many binary formats.
+///
+/// The items follow the header directly, after any padding their alignment requires.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::LenPrefixed;
+///
+/// let mut buffer = [0u8; 64];
+/// let (len, items, _remainder) =
+///     LenPrefixed::<u32, u16>::alloc_zeroed_len_prefixed(&mut buffer, 8).unwrap();
+///
+/// *len = items.len() as u32;
+/// assert_eq!(items, [0; 8]);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LenPrefixed<H, T>(PhantomData<(H, T)>);
+
+impl<H: AllocZeroed, T: AllocZeroed> LenPrefixed<H, T> {
+    /// Allocates a zero-initialized header followed by `count` zero-initialized items,
+    /// returning both and the remainder of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer cannot hold the header and the items. The header may
+    /// have been zeroed when the items do not fit.
+    #[allow(clippy::type_complexity)]
+    pub fn alloc_zeroed_len_prefixed(
+        mem: &mut [u8],
+        count: usize,
+    ) -> Result<(&mut H, &mut [T], &mut [u8]), AllocError> {
+        let (header, rest) = H::alloc_zeroed_with_remainder(mem)?;
+        let (items, remainder) = T::alloc_zeroed_slice_with_remainder(rest, count)?;
+
+        Ok((header, items, remainder))
+    }
+}
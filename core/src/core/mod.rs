@@ -1,15 +1,393 @@
 #[cfg(feature = "derive")]
 pub use alloc_zeroed_macros::AllocZeroed;
 
+use core::mem::MaybeUninit;
+
+pub mod aligned_buffer;
+pub mod allocator;
+pub mod dual_view;
 #[macro_use]
 pub mod error;
+pub mod filled;
 pub mod implementations;
+pub mod len_prefixed;
+pub mod planner;
+pub mod reclaimable;
+pub mod sink;
+pub mod slab;
+pub mod slice_alloc;
+pub mod strategy;
+pub mod typed_buffer;
+
+pub use aligned_buffer::{AlignedBuffer, Alignment, SupportedAlignment};
+pub use allocator::{BufferAllocator, TxAllocator};
+pub use dual_view::DualView;
+pub use error::{
+    AllocError, AllocErrorKind, AllocErrorTag, BOXED_METHOD_ERRORS, BUFFER_METHOD_ERRORS,
+    MAX_CONTEXTS,
+};
+pub use filled::AllocFilled;
+pub use len_prefixed::LenPrefixed;
+pub use planner::LayoutPlanner;
+pub use reclaimable::Reclaimable;
+pub use sink::ZeroSink;
+pub use slab::{Slab, SlabKey};
+pub use slice_alloc::SliceAlloc;
+pub use strategy::{AllocStrategy, BufferStrategy};
+pub use typed_buffer::TypedBuffer;
+
+/// Rejects allocations larger than `isize::MAX` bytes, which no Rust object may exceed.
+pub(crate) fn check_layout(size: usize, alignment: usize) -> Result<(), AllocError> {
+    if size > isize::MAX as usize {
+        return Err(AllocError::invalid_layout(size, alignment).build());
+    }
+
+    Ok(())
+}
+
+/// Computes where `count` elements of `T` would be placed in `mem` without touching it.
+///
+/// Returns the alignment padding before the first element and the total size of the
+/// elements in bytes, or the error an allocation into `mem` would fail with. Zero-sized
+/// types and empty requests always fit without padding.
+///
+/// Every typed allocation computes its layout here, so this is where attempts are
+/// recorded in the diagnostic log.
+pub(crate) fn slice_layout<T>(mem: &[u8], count: usize) -> Result<(usize, usize), AllocError> {
+    let size = size_of::<T>();
+
+    let layout = if size == 0 || count == 0 {
+        Ok((0, 0))
+    } else {
+        // Saturate so that an overflowing request is reported as an invalid layout
+        let total_bytes = size.saturating_mul(count);
+        region_layout(mem, total_bytes, align_of::<T>()).map(|offset| (offset, total_bytes))
+    };
+
+    #[cfg(feature = "diag")]
+    record_attempt::<T>(mem, count, layout.is_ok());
+
+    layout
+}
+
+/// Zeroes the `count` values of `T` placed `offset` bytes into `mem` and returns them
+/// together with the bytes after them.
+///
+/// # Safety
+///
+/// Unless `T` is zero-sized or `count` is zero, the `count` values at `offset` must lie
+/// within `mem` and be aligned for `T`, as [`slice_layout`] checks.
+pub(crate) unsafe fn zeroed_slice_at<T: AllocZeroed>(
+    mem: &mut [u8],
+    offset: usize,
+    count: usize,
+) -> (&mut [T], &mut [u8]) {
+    if size_of::<T>() == 0 || count == 0 {
+        // SAFETY: Zero-sized values need no memory, so a dangling pointer is valid for any
+        // length up to the `isize::MAX` limit of slices
+        let slice = unsafe {
+            core::slice::from_raw_parts_mut(
+                core::ptr::NonNull::<T>::dangling().as_ptr(),
+                count.min(isize::MAX as usize),
+            )
+        };
+        return (slice, mem);
+    }
+
+    let (alloc_slice, remainder) = mem[offset..].split_at_mut(size_of::<T>() * count);
+
+    // Zero the memory
+    alloc_slice.fill(0);
+
+    // SAFETY: The caller ensured the region is aligned and large enough, and zeroed memory
+    // is valid for T (guaranteed by AllocZeroed trait bound)
+    unsafe {
+        let ptr = alloc_slice.as_mut_ptr() as *mut T;
+        (core::slice::from_raw_parts_mut(ptr, count), remainder)
+    }
+}
+
+/// Returns the number of bytes to skip from `ptr` to reach an address aligned to `align`.
+///
+/// [`pointer::align_offset`] is allowed to give up and return `usize::MAX` even when
+/// alignment is possible, which Miri does on purpose to catch code relying on it. In that
+/// case the padding is computed from the address instead, and `usize::MAX` is only returned
+/// for an `align` that is not a power of two.
+pub(crate) fn align_padding(ptr: *const u8, align: usize) -> usize {
+    resolve_padding(ptr.align_offset(align), ptr.addr(), align)
+}
+
+/// The fallback behind [`align_padding`], split out so that it can be tested with a
+/// pessimistic `offset`.
+pub(crate) fn resolve_padding(offset: usize, addr: usize, align: usize) -> usize {
+    if offset != usize::MAX || !align.is_power_of_two() {
+        return offset;
+    }
+
+    addr.wrapping_neg() & (align - 1)
+}
+
+/// Computes the alignment padding needed to place a `size`-byte region aligned to `align`
+/// at the start of `mem`, or the error an allocation into `mem` would fail with.
+pub(crate) fn region_layout(mem: &[u8], size: usize, align: usize) -> Result<usize, AllocError> {
+    let mem_ptr = mem.as_ptr();
+    let offset = align_padding(mem_ptr, align);
+
+    if offset == usize::MAX {
+        return Err(AllocError::builder(AllocErrorKind::AlignmentFailed {
+            required_alignment: align,
+            address: mem_ptr as usize,
+        })
+        .build());
+    }
+
+    check_layout(size, align)?;
+
+    let available_bytes = mem.len().saturating_sub(offset);
+    if offset > mem.len() || available_bytes < size {
+        let mut error = AllocError::builder(AllocErrorKind::BufferTooSmall {
+            required: size,
+            available: available_bytes,
+            alignment: align,
+            padding: offset,
+        });
+
+        if mem.len() >= size {
+            error = error.with_context(
+                "the buffer is large enough but its start is not aligned for the type; \
+                 use an aligned buffer or one of at least size + alignment - 1 bytes",
+            );
+        }
+
+        return Err(error.build());
+    }
+
+    Ok(offset)
+}
+
+/// Returns how many complete `T` values fit in `mem` after alignment padding, or the
+/// error to report when not even one does. Zero-sized types fit `isize::MAX` times, the
+/// longest slice length the standard library allows.
+///
+/// A failure is recorded in the diagnostic log as an attempt to allocate one `T`.
+pub(crate) fn max_count<T>(mem: &[u8]) -> Result<usize, AllocError> {
+    let count = fitting_count::<T>(mem);
+
+    if count == 0 {
+        #[cfg(feature = "diag")]
+        record_attempt::<T>(mem, 1, false);
+
+        let align = align_of::<T>();
+        let offset = align_padding(mem.as_ptr(), align);
+        return Err(AllocError::builder(AllocErrorKind::BufferTooSmall {
+            required: size_of::<T>(),
+            available: mem.len().saturating_sub(offset),
+            alignment: align,
+            padding: offset,
+        })
+        .build());
+    }
+
+    Ok(count)
+}
+
+/// Returns how many complete `T` values fit in `mem` after alignment padding, possibly
+/// zero. Unlike [`max_count`], this neither builds an error nor records an attempt, for
+/// callers that only probe before allocating.
+fn fitting_count<T>(mem: &[u8]) -> usize {
+    let offset = align_padding(mem.as_ptr(), align_of::<T>());
+    let available_bytes = mem.len().saturating_sub(offset);
+
+    // Calculate how many complete items we can fit
+    available_bytes
+        .checked_div(size_of::<T>())
+        .unwrap_or(isize::MAX as usize)
+}
+
+/// Buffers longer than this are almost certainly built from a bogus length, e.g. a
+/// negative value cast to `usize`.
+#[cfg(feature = "log")]
+pub(crate) const SUSPICIOUS_BUFFER_LEN: usize = isize::MAX as usize / 2;
+
+/// Logs a warning in debug builds when a buffer of `len` bytes is suspiciously large.
+#[cfg(feature = "log")]
+pub(crate) fn warn_if_oversized(len: usize) {
+    if cfg!(debug_assertions) && len > SUSPICIOUS_BUFFER_LEN {
+        log::warn!(
+            "allocating from a buffer of {} bytes, which is suspiciously large; \
+             check how the buffer was constructed",
+            len
+        );
+    }
+}
+
+/// Records an attempt to allocate `count` values of `T` from `mem` in the thread's
+/// diagnostic log.
+#[cfg(feature = "diag")]
+pub(crate) fn record_attempt<T>(mem: &[u8], count: usize, success: bool) {
+    crate::std::diag::record(crate::std::diag::DiagRecord {
+        type_name: core::any::type_name::<T>(),
+        size: size_of::<T>().saturating_mul(count),
+        offset: align_padding(mem.as_ptr(), align_of::<T>()),
+        success,
+    });
+}
+
+/// Zeroes the first `n` bytes of `mem` and returns them.
+///
+/// This is the byte-buffer case of [`AllocZeroed::alloc_zeroed_slice_with_remainder`] for
+/// `u8`, without the alignment and layout computations that bytes never need.
+///
+/// # Errors
+///
+/// Returns `AllocError::BufferTooSmall` if `mem` is shorter than `n` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::alloc_zeroed_bytes;
+///
+/// let mut buffer = [0xFFu8; 16];
+/// let bytes = alloc_zeroed_bytes(&mut buffer, 4).unwrap();
+/// assert_eq!(bytes, [0; 4]);
+/// assert_eq!(buffer[4], 0xFF);
+/// ```
+pub fn alloc_zeroed_bytes(mem: &mut [u8], n: usize) -> Result<&mut [u8], AllocError> {
+    let len = mem.len();
+    let bytes = mem
+        .get_mut(..n)
+        .ok_or_else(|| AllocError::buffer_too_small(n, len, 1).build())?;
+
+    bytes.fill(0);
+
+    Ok(bytes)
+}
+
+/// Carves one zero-initialized slice per listed type out of a buffer, for struct-of-arrays
+/// layouts.
+///
+/// Takes the buffer followed by `Type: count` pairs and evaluates to
+/// `Result<((&mut [Type1], &mut [Type2], ...), &mut [u8]), AllocError>`: the column slices in
+/// order, then the remainder. Each slice starts at the next address aligned for its type.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::soa_alloc_zeroed;
+///
+/// let mut buffer = [0xFFu8; 256];
+/// let ((ids, positions, flags), _remainder) =
+///     soa_alloc_zeroed!(&mut buffer, u32: 8, f64: 8, bool: 8).unwrap();
+///
+/// assert_eq!(ids, [0; 8]);
+/// assert_eq!(positions, [0.0; 8]);
+/// assert_eq!(flags, [false; 8]);
+/// ```
+#[macro_export]
+macro_rules! soa_alloc_zeroed {
+    (@carve $label:lifetime, $rest:ident, [$($done:ident),*]; $ty:ty : $count:expr $(, $more_ty:ty : $more_count:expr)*) => {{
+        let (column, rest) =
+            match <$ty as $crate::AllocZeroed>::alloc_zeroed_slice_with_remainder($rest, $count) {
+                ::core::result::Result::Ok(carved) => carved,
+                ::core::result::Result::Err(err) => break $label ::core::result::Result::Err(err),
+            };
+        $crate::soa_alloc_zeroed!(@carve $label, rest, [$($done,)* column]; $($more_ty : $more_count),*)
+    }};
+    (@carve $label:lifetime, $rest:ident, [$($done:ident),*];) => {
+        ::core::result::Result::Ok((($($done,)*), $rest))
+    };
+    ($mem:expr, $($ty:ty : $count:expr),+ $(,)?) => {{
+        let mem: &mut [u8] = $mem;
+        // A labeled block rather than a closure lets errors return early without capturing
+        // the variables used in the counts
+        'soa: {
+            $crate::soa_alloc_zeroed!(@carve 'soa, mem, []; $($ty : $count),+)
+        }
+    }};
+}
+
+/// Evaluates to the largest alignment among the given types.
+///
+/// Aligning a shared buffer to this value avoids padding before the first allocation of any
+/// of the types. The macro can be used in constant expressions.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::max_align;
+///
+/// const ALIGN: usize = max_align!(u8, u64, u16);
+/// assert_eq!(ALIGN, align_of::<u64>());
+/// ```
+#[macro_export]
+macro_rules! max_align {
+    ($($ty:ty),+ $(,)?) => {{
+        let mut max = 1;
+        $(
+            if ::core::mem::align_of::<$ty>() > max {
+                max = ::core::mem::align_of::<$ty>();
+            }
+        )+
+        max
+    }};
+}
+
+/// Evaluates to a buffer size that fits one value of each given type, allocated in order,
+/// whatever the address of the buffer.
+///
+/// The exact padding between the values depends on where the buffer ends up, so this is the
+/// upper bound: the sum of the sizes plus `align_of::<T>() - 1` bytes of padding per type.
+/// The macro can be used in constant expressions, such as an array length.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::{AllocZeroed, const_buffer_size};
+///
+/// let mut buffer = [0u8; const_buffer_size!(u8, u64, [u16; 3])];
+/// let (_, rest) = u8::alloc_zeroed_with_remainder(&mut buffer).unwrap();
+/// let (_, rest) = u64::alloc_zeroed_with_remainder(rest).unwrap();
+/// assert!(<[u16; 3]>::alloc_zeroed(rest).is_ok());
+/// ```
+#[macro_export]
+macro_rules! const_buffer_size {
+    ($($ty:ty),+ $(,)?) => {
+        0 $(
+            + ::core::mem::size_of::<$ty>() + (::core::mem::align_of::<$ty>() - 1)
+        )+
+    };
+}
 
-pub use error::{AllocError, AllocErrorKind};
+/// Returns `true` if every byte of `mem` is zero.
+pub fn is_buffer_zeroed(mem: &[u8]) -> bool {
+    mem.iter().all(|&byte| byte == 0)
+}
+
+/// Zeroes `mem` with volatile writes followed by a compiler fence, so the stores cannot be
+/// elided or reordered past later accesses.
+pub(crate) fn volatile_zero(mem: &mut [u8]) {
+    for byte in mem.iter_mut() {
+        // SAFETY: `byte` is a valid, exclusive reference to a single `u8`
+        unsafe { core::ptr::write_volatile(byte, 0) };
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
 
 /// # Safety
 /// All-zero pattern must be a valid value of type.
+///
+/// Types with a non-null invariant, such as references, `Box` or [`NonNull`], must never
+/// implement this trait. Wrap them in `Option` instead, where null represents `None`; this
+/// crate implements the trait for `Option<NonNull<T>>`.
+///
+/// [`NonNull`]: core::ptr::NonNull
 pub unsafe trait AllocZeroed: Sized {
+    /// `true` when the impl was generated by `#[derive(AllocZeroed)]`.
+    ///
+    /// Derived impls are checked at compile time to only contain `AllocZeroed` fields, so
+    /// generic code can treat them as trusted. Hand-written impls keep the default `false`.
+    const IS_TRIVIALLY_ZEROABLE: bool = false;
+
     /// Allocates and zero-initializes an instance of `Self` in the provided buffer.
     ///
     /// This method attempts to allocate memory for `Self` within the given byte buffer,
@@ -18,13 +396,13 @@ pub unsafe trait AllocZeroed: Sized {
     /// # Parameters
     ///
     /// * `mem` - A mutable byte slice where the object will be allocated. The buffer must
-    ///           be large enough to accommodate the type's size and alignment requirements.
+    ///   be large enough to accommodate the type's size and alignment requirements.
     ///
     /// # Returns
     ///
     /// * `Ok(&mut Self)` - A mutable reference to the zero-initialized object if allocation succeeds.
     /// * `Err(AllocError)` - An error describing why allocation failed (insufficient space,
-    ///                       alignment issues, or invalid layout).
+    ///   alignment issues, or invalid layout).
     ///
     /// # Errors
     ///
@@ -65,6 +443,450 @@ pub unsafe trait AllocZeroed: Sized {
         Ok(element)
     }
 
+    /// Returns the buffer size that guarantees an allocation of `Self` succeeds, whatever the
+    /// alignment of the buffer.
+    ///
+    /// This is `size_of::<Self>() + align_of::<Self>() - 1`, leaving room for the worst-case
+    /// alignment padding, and 0 for zero-sized types. It matters most for over-aligned types
+    /// such as `#[repr(align(4096))]` pages, where a plain byte buffer may need up to 4095
+    /// bytes of padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// assert_eq!(u64::alloc_zeroed_requires_aligned_buffer(), 15);
+    ///
+    /// let mut buffer = [0u8; 15];
+    /// assert!(u64::alloc_zeroed(&mut buffer).is_ok());
+    /// ```
+    fn alloc_zeroed_requires_aligned_buffer() -> usize {
+        if size_of::<Self>() == 0 {
+            return 0;
+        }
+
+        size_of::<Self>() + (align_of::<Self>() - 1)
+    }
+
+    /// Returns the exact number of bytes an allocation of `Self` would consume from the
+    /// start of `mem`, alignment padding included, or `None` if it would not fit.
+    ///
+    /// Unlike [`alloc_zeroed_requires_aligned_buffer`], which is the worst case for any
+    /// buffer, this looks at the actual address of `mem`, so a caller managing their own
+    /// cursor can advance it by exactly this amount. Zero-sized types consume 0 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// #[repr(align(8))]
+    /// struct Aligned([u8; 16]);
+    ///
+    /// let buffer = Aligned([0; 16]);
+    /// assert_eq!(u32::consumed_bytes(&buffer.0), Some(4));
+    /// assert_eq!(u32::consumed_bytes(&buffer.0[1..]), Some(7));
+    /// assert_eq!(u64::consumed_bytes(&buffer.0[1..12]), None);
+    /// ```
+    ///
+    /// [`alloc_zeroed_requires_aligned_buffer`]: AllocZeroed::alloc_zeroed_requires_aligned_buffer
+    fn consumed_bytes(mem: &[u8]) -> Option<usize> {
+        if size_of::<Self>() == 0 {
+            return Some(0);
+        }
+
+        let offset = align_padding(mem.as_ptr(), align_of::<Self>());
+        let consumed = offset.checked_add(size_of::<Self>())?;
+
+        (consumed <= mem.len()).then_some(consumed)
+    }
+
+    /// Returns the alignment that `field`, a field of `base`, actually has in memory: the
+    /// largest power of two dividing its address.
+    ///
+    /// This is a debugging aid for checking layout assumptions after an allocation, e.g.
+    /// that a field of an FFI struct is at least as aligned as the foreign side expects.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `field` does not lie within `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// #[derive(AllocZeroed)]
+    /// #[repr(C)]
+    /// struct Record {
+    ///     tag: u8,
+    ///     value: u64,
+    /// }
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let record = Record::alloc_zeroed(&mut buffer).unwrap();
+    /// assert!(Record::field_alignment(record, &record.value) >= 8);
+    /// ```
+    fn field_alignment<U>(base: &Self, field: &U) -> usize {
+        let base_addr = (base as *const Self).addr();
+        let field_addr = (field as *const U).addr();
+
+        debug_assert!(
+            field_addr >= base_addr && field_addr + size_of::<U>() <= base_addr + size_of::<Self>(),
+            "`field` is not part of `base`"
+        );
+
+        1 << field_addr.trailing_zeros()
+    }
+
+    /// Allocates and zero-initializes an instance of `Self` in a `'static` buffer, returning a
+    /// `'static` reference.
+    ///
+    /// This is meant for singletons initialized once at startup from a static buffer, and
+    /// spares user code from extending the lifetime of the result unsafely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let buffer: &'static mut [u8] = Box::leak(Box::new([0u8; 64]));
+    /// let config: &'static mut [u32; 4] = <[u32; 4]>::alloc_zeroed_leak(buffer).unwrap();
+    /// assert_eq!(*config, [0; 4]);
+    /// ```
+    fn alloc_zeroed_leak(mem: &'static mut [u8]) -> Result<&'static mut Self, AllocError>
+    where
+        Self: 'static,
+    {
+        Self::alloc_zeroed(mem)
+    }
+
+    /// Allocates and zero-initializes an instance of `Self`, together with a closure that
+    /// resets it to zero for reuse.
+    ///
+    /// The closure takes the object back as an argument rather than holding on to it, so
+    /// the borrow checker still guarantees that no other reference observes the reset. It
+    /// overwrites the bytes without running the destructor of the old value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let (counters, reset) = <[u32; 4]>::alloc_zeroed_scoped(&mut buffer).unwrap();
+    ///
+    /// counters[2] = 9;
+    /// reset(counters);
+    /// assert_eq!(*counters, [0; 4]);
+    /// ```
+    fn alloc_zeroed_scoped(mem: &mut [u8]) -> Result<(&mut Self, impl Fn(&mut Self)), AllocError> {
+        let value = Self::alloc_zeroed(mem)?;
+
+        let reset = |value: &mut Self| {
+            // SAFETY: `value` is valid for writes of one `Self`, and all-zero bytes are a
+            // valid `Self` per the trait contract
+            unsafe { core::ptr::write_bytes(value as *mut Self, 0, 1) };
+        };
+
+        Ok((value, reset))
+    }
+
+    /// Allocates and zero-initializes an instance of `Self` in the buffer, returning a raw
+    /// [`NonNull`] pointer instead of a reference.
+    ///
+    /// This is meant for intrusive data structures, where nodes point at each other and a
+    /// `&mut Self` would be too restrictive. For zero-sized types the pointer is
+    /// [`NonNull::dangling`].
+    ///
+    /// # Safety of the returned pointer
+    ///
+    /// The pointer is not tied to the lifetime of `mem`. The caller must ensure that:
+    ///
+    /// * the buffer outlives every use of the pointer, and
+    /// * the buffer is not accessed through `mem` (or any other path) while the pointer is
+    ///   used to read or write the object, since that would invalidate it under Rust's
+    ///   aliasing rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 16];
+    /// let ptr = u32::alloc_zeroed_nonnull(&mut buffer).unwrap();
+    /// // SAFETY: `buffer` is alive and not otherwise accessed
+    /// unsafe {
+    ///     *ptr.as_ptr() = 5;
+    ///     assert_eq!(*ptr.as_ptr(), 5);
+    /// }
+    /// ```
+    ///
+    /// [`NonNull`]: core::ptr::NonNull
+    /// [`NonNull::dangling`]: core::ptr::NonNull::dangling
+    /// [`alloc_zeroed`]: AllocZeroed::alloc_zeroed
+    fn alloc_zeroed_nonnull(mem: &mut [u8]) -> Result<core::ptr::NonNull<Self>, AllocError> {
+        Self::alloc_zeroed(mem).map(core::ptr::NonNull::from)
+    }
+
+    /// Allocates and zero-initializes an instance of `Self`, returning a [`DualView`] that
+    /// gives access to it both through a typed pointer and as raw bytes.
+    ///
+    /// This suits parsers that set fields and then checksum the bytes. A plain
+    /// `(NonNull<Self>, &mut [u8])` pair cannot be used alternately without invalidating one
+    /// of them under Rust's aliasing rules, so the view derives each access from the same
+    /// raw pointer instead. Only one view may be used at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`](AllocZeroed::alloc_zeroed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 16];
+    /// let mut view = u32::alloc_zeroed_dual(&mut buffer).unwrap();
+    ///
+    /// // SAFETY: The pointer is valid and no byte view is alive
+    /// unsafe { *view.as_ptr().as_ptr() = u32::from_ne_bytes([1, 2, 3, 4]) };
+    /// // SAFETY: `u32` has no padding, so every byte is initialized
+    /// assert_eq!(unsafe { view.bytes() }, [1, 2, 3, 4]);
+    /// ```
+    fn alloc_zeroed_dual(mem: &mut [u8]) -> Result<DualView<'_, Self>, AllocError> {
+        let ptr = Self::alloc_zeroed_nonnull(mem)?;
+
+        // SAFETY: `ptr` points to a zeroed `Self`, so every byte is initialized, and it
+        // borrows `mem` exclusively for the lifetime of the view
+        Ok(unsafe { DualView::new(ptr) })
+    }
+
+    /// Allocates an instance of `Self` in a buffer region the caller claims is already zeroed.
+    ///
+    /// Behaves like [`alloc_zeroed`], but first checks that the bytes the object will occupy
+    /// are all zero. This is intended for security-sensitive code that wipes buffers between
+    /// uses and wants to detect a missed wipe.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::DirtyBuffer` with the offset (relative to `mem`) of the first
+    /// non-zero byte in the region, or any error [`alloc_zeroed`] would return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 16];
+    /// assert!(u32::alloc_zeroed_verify_clean(&mut buffer).is_ok());
+    /// ```
+    ///
+    /// [`alloc_zeroed`]: AllocZeroed::alloc_zeroed
+    fn alloc_zeroed_verify_clean(mem: &mut [u8]) -> Result<&mut Self, AllocError> {
+        let (offset, size) = slice_layout::<Self>(mem, 1)?;
+
+        if let Some(position) = mem[offset..offset + size].iter().position(|&b| b != 0) {
+            return Err(AllocError::builder(AllocErrorKind::DirtyBuffer {
+                offset: offset + position,
+            })
+            .build());
+        }
+
+        // SAFETY: `slice_layout` placed one `Self` at `offset`
+        let (value, _) = unsafe { zeroed_slice_at::<Self>(mem, offset, 1) };
+
+        Ok(&mut value[0])
+    }
+
+    /// Allocates an instance of `Self` whose zeroing is guaranteed to reach memory.
+    ///
+    /// Behaves like [`alloc_zeroed`], but the object's bytes are additionally cleared with
+    /// volatile writes followed by a compiler fence. This prevents the optimizer from eliding
+    /// the zeroing, which makes it suitable for clearing buffers that previously held secrets.
+    ///
+    /// This is slower than [`alloc_zeroed`], since every byte is written individually. It gives
+    /// the same compiler-level guarantee as the `zeroize` crate, but like `zeroize` it does not
+    /// flush CPU caches or scrub copies of the data that live elsewhere (registers, swap, or
+    /// other buffers).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xA5u8; 64];
+    /// let key = <[u8; 32]>::alloc_zeroed_secure(&mut buffer).unwrap();
+    /// assert_eq!(*key, [0; 32]);
+    /// ```
+    ///
+    /// [`alloc_zeroed`]: AllocZeroed::alloc_zeroed
+    fn alloc_zeroed_secure(mem: &mut [u8]) -> Result<&mut Self, AllocError> {
+        let element = Self::alloc_zeroed(mem)?;
+
+        // SAFETY: `element` is a valid, exclusive reference, so its bytes may be viewed
+        // mutably; writing zeros keeps it valid (guaranteed by AllocZeroed)
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(element as *mut Self as *mut u8, size_of::<Self>())
+        };
+        volatile_zero(bytes);
+
+        Ok(element)
+    }
+
+    /// Reserves an aligned slice of `count` uninitialized `Self` slots in the buffer.
+    ///
+    /// Unlike the other allocation methods, the memory is *not* zeroed. This supports
+    /// allocating first and initializing en masse later, e.g. with [`init_all`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed_slice_with_remainder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xFFu8; 64];
+    /// let slots = u32::alloc_zeroed_maybe_uninit_slice(&mut buffer, 8).unwrap();
+    /// let values = u32::init_all(slots);
+    /// assert_eq!(values, [0; 8]);
+    /// ```
+    ///
+    /// [`init_all`]: AllocZeroed::init_all
+    /// [`alloc_zeroed_slice_with_remainder`]: AllocZeroed::alloc_zeroed_slice_with_remainder
+    fn alloc_zeroed_maybe_uninit_slice(
+        mem: &mut [u8],
+        count: usize,
+    ) -> Result<&mut [MaybeUninit<Self>], AllocError> {
+        let (offset, total_bytes) = slice_layout::<Self>(mem, count)?;
+
+        if size_of::<Self>() == 0 || count == 0 {
+            let ptr = core::ptr::NonNull::<MaybeUninit<Self>>::dangling().as_ptr();
+            // SAFETY: Zero-sized slots need no storage, only an aligned pointer
+            return Ok(unsafe { core::slice::from_raw_parts_mut(ptr, count) });
+        }
+
+        let slots = &mut mem[offset..offset + total_bytes];
+
+        // SAFETY: The region is aligned for `Self`, large enough for `count` elements, and
+        // any bytes are a valid `MaybeUninit<Self>`
+        Ok(unsafe {
+            core::slice::from_raw_parts_mut(slots.as_mut_ptr() as *mut MaybeUninit<Self>, count)
+        })
+    }
+
+    /// Zero-initializes every slot and returns them as initialized values.
+    ///
+    /// This is safe because an all-zero bit pattern is a valid `Self`.
+    fn init_all(slots: &mut [MaybeUninit<Self>]) -> &mut [Self] {
+        // SAFETY: The slots are valid for writes, and zeroed memory is a valid `Self`
+        // (guaranteed by AllocZeroed trait bound)
+        unsafe {
+            core::ptr::write_bytes(slots.as_mut_ptr(), 0, slots.len());
+            core::slice::from_raw_parts_mut(slots.as_mut_ptr() as *mut Self, slots.len())
+        }
+    }
+
+    /// Allocates and zero-initializes an instance of `Self`, returning a [`Reclaimable`] that
+    /// gives the whole buffer back once the object is no longer needed.
+    ///
+    /// [`Reclaimable::reclaim`] returns all of `mem`, alignment padding included, so the
+    /// space can be reused for another allocation. The object's destructor is not run.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`](AllocZeroed::alloc_zeroed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xFFu8; 64];
+    /// let mut value = u64::alloc_zeroed_reclaimable(&mut buffer).unwrap();
+    /// *value = 7;
+    ///
+    /// let mem = value.reclaim();
+    /// assert_eq!(mem.len(), 64);
+    /// assert!(<[u32; 8]>::alloc_zeroed(mem).is_ok());
+    /// ```
+    fn alloc_zeroed_reclaimable(mem: &mut [u8]) -> Result<Reclaimable<'_, Self>, AllocError> {
+        let (offset, size) = slice_layout::<Self>(mem, 1)?;
+        let len = mem.len();
+        let base = core::ptr::NonNull::from(mem).cast::<u8>();
+
+        // SAFETY: `slice_layout` checked that the `size` bytes at `offset` lie within the
+        // buffer and are aligned for `Self`
+        unsafe { core::ptr::write_bytes(base.as_ptr().add(offset), 0, size) };
+
+        // SAFETY: `base` is the buffer borrowed exclusively for the returned lifetime, and
+        // the zeroed bytes at `offset` are a valid `Self`
+        Ok(unsafe { Reclaimable::new(base, len, offset) })
+    }
+
+    /// Allocates a zero-initialized `Self` in the buffer and copies `src` over its leading bytes.
+    ///
+    /// Bytes of `Self` past the end of `src` stay zero, which suits deserializing fixed-size
+    /// records whose payload may be shorter than the record.
+    ///
+    /// # Safety
+    ///
+    /// `AllocZeroed` only promises that the all-zero pattern is a valid `Self`. The caller must
+    /// guarantee that the bytes of `src` followed by zeros also form a valid `Self`, e.g. that
+    /// `Self` has no `bool`, `char` or enum fields receiving arbitrary bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::BufferTooSmall` if `src` is longer than `Self`, or if the buffer
+    /// cannot hold `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// // SAFETY: every byte pattern is a valid `[u8; 8]`
+    /// let record = unsafe { <[u8; 8]>::alloc_zeroed_from_bytes(&mut buffer, b"abc") }.unwrap();
+    /// assert_eq!(record, b"abc\0\0\0\0\0");
+    /// ```
+    unsafe fn alloc_zeroed_from_bytes<'a>(
+        mem: &'a mut [u8],
+        src: &[u8],
+    ) -> Result<&'a mut Self, AllocError> {
+        let size = size_of::<Self>();
+
+        if src.len() > size {
+            return Err(
+                AllocError::buffer_too_small(src.len(), size, align_of::<Self>())
+                    .with_type_name(core::any::type_name::<Self>())
+                    .with_context("source bytes do not fit in the type")
+                    .build(),
+            );
+        }
+
+        let value = Self::alloc_zeroed(mem)?;
+
+        // SAFETY: `value` is valid for `size` bytes and `src` is no longer than that; the
+        // resulting bytes are a valid `Self` as upheld by the caller
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), value as *mut Self as *mut u8, src.len());
+        }
+
+        Ok(value)
+    }
+
     /// Allocates and zero-initializes an instance of `Self` in the provided buffer, returning the remainder.
     ///
     /// This method allocates memory for a single instance of `Self` within the given byte buffer,
@@ -75,7 +897,7 @@ pub unsafe trait AllocZeroed: Sized {
     /// # Parameters
     ///
     /// * `mem` - A mutable byte slice where the object will be allocated. The buffer must
-    ///           be large enough to accommodate the type's size and alignment requirements.
+    ///   be large enough to accommodate the type's size and alignment requirements.
     ///
     /// # Returns
     ///
@@ -148,6 +970,190 @@ pub unsafe trait AllocZeroed: Sized {
         Ok((slice.first_mut().unwrap(), remainder))
     }
 
+    /// Allocates and zero-initializes an instance of `Self`, returning it together with the
+    /// number of bytes consumed from the start of `mem`, alignment padding included.
+    ///
+    /// Callers managing their own offset into a larger buffer can advance it by that amount.
+    /// As with every method of this trait, `mem` is only borrowed, so after a failure the
+    /// same buffer can be used to retry, e.g. with a smaller type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`](AllocZeroed::alloc_zeroed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let mut offset = 0;
+    ///
+    /// let (_, used) = u16::alloc_zeroed_consumed(&mut buffer[offset..]).unwrap();
+    /// offset += used;
+    /// let (_, used) = u64::alloc_zeroed_consumed(&mut buffer[offset..]).unwrap();
+    /// offset += used;
+    /// assert!(offset >= 10);
+    /// ```
+    fn alloc_zeroed_consumed(mem: &mut [u8]) -> Result<(&mut Self, usize), AllocError> {
+        let len = mem.len();
+        let (value, remainder) = Self::alloc_zeroed_with_remainder(mem)?;
+        let consumed = len - remainder.len();
+
+        Ok((value, consumed))
+    }
+
+    /// Allocates and zero-initializes an instance of `Self`, returning it together with a
+    /// pointer one past its last byte.
+    ///
+    /// This is a debugging aid for asserting that writes stay within the object. The end
+    /// pointer must only be compared, never dereferenced.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`](AllocZeroed::alloc_zeroed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 32];
+    /// let (value, end) = <[u16; 4]>::alloc_zeroed_with_bounds(&mut buffer).unwrap();
+    /// assert_eq!(end as usize - value.as_ptr() as usize, 8);
+    /// ```
+    fn alloc_zeroed_with_bounds(mem: &mut [u8]) -> Result<(&mut Self, *const u8), AllocError> {
+        let value = Self::alloc_zeroed(mem)?;
+        let end = (value as *const Self)
+            .cast::<u8>()
+            .wrapping_add(size_of::<Self>());
+
+        Ok((value, end))
+    }
+
+    /// Allocates and zero-initializes an instance of `Self`, returning it together with a
+    /// [`BufferAllocator`] over the rest of the buffer for further allocations.
+    ///
+    /// The allocator's [`position`](BufferAllocator::position) already counts the bytes
+    /// taken by `Self`, so offsets stay relative to the start of `mem`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`](AllocZeroed::alloc_zeroed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xFFu8; 128];
+    /// let (len, mut rest) = u32::alloc_zeroed_then(&mut buffer).unwrap();
+    /// let payload = rest.alloc_slice::<u16>(8).unwrap();
+    ///
+    /// *len = payload.len() as u32;
+    /// assert_eq!(payload, [0; 8]);
+    /// ```
+    fn alloc_zeroed_then(mem: &mut [u8]) -> Result<(&mut Self, BufferAllocator<'_>), AllocError> {
+        let mut allocator = BufferAllocator::new(mem);
+        let value = allocator.alloc::<Self>()?;
+
+        Ok((value, allocator))
+    }
+
+    /// Allocates and zero-initializes an instance of `Self`, then splits the rest of the
+    /// buffer into two halves, e.g. to recurse into each half of a divide-and-conquer
+    /// algorithm.
+    ///
+    /// The halves are plain byte slices with no alignment of their own. When the rest has
+    /// an odd length, the second half gets the extra byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed`](AllocZeroed::alloc_zeroed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let (node, left, right) = u32::alloc_zeroed_and_split(&mut buffer).unwrap();
+    /// assert_eq!(*node, 0);
+    /// assert!(right.len() - left.len() <= 1);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn alloc_zeroed_and_split(
+        mem: &mut [u8],
+    ) -> Result<(&mut Self, &mut [u8], &mut [u8]), AllocError> {
+        let (value, remainder) = Self::alloc_zeroed_with_remainder(mem)?;
+        let (left, right) = remainder.split_at_mut(remainder.len() / 2);
+
+        Ok((value, left, right))
+    }
+
+    /// Allocates a zero-initialized `Self` starting at byte `start` of the buffer, returning
+    /// the remainder after the object.
+    ///
+    /// The first `start` bytes are left untouched, e.g. for a header written later. Alignment
+    /// padding is counted from `start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::BufferTooSmall` if `start` is past the end of the buffer or the
+    /// rest of the buffer cannot hold `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xFFu8; 64];
+    /// let (value, remainder) = u32::alloc_zeroed_at(&mut buffer, 16).unwrap();
+    /// assert_eq!(*value, 0);
+    /// assert!(remainder.len() <= 64 - 16 - 4);
+    /// assert_eq!(buffer[..16], [0xFF; 16]);
+    /// ```
+    fn alloc_zeroed_at(mem: &mut [u8], start: usize) -> Result<(&mut Self, &mut [u8]), AllocError> {
+        if start > mem.len() {
+            return Err(
+                AllocError::buffer_too_small(start, mem.len(), align_of::<Self>())
+                    .with_type_name(core::any::type_name::<Self>())
+                    .with_context("start offset is past the end of the buffer")
+                    .build(),
+            );
+        }
+
+        Self::alloc_zeroed_with_remainder(&mut mem[start..])
+    }
+
+    /// Allocates a zero-initialized `Self`, also returning the alignment padding skipped
+    /// before it and the remainder after it.
+    ///
+    /// The padding bytes are returned as they were, so they can be checked or filled with a
+    /// marker. Zero-sized types take no padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xFFu8; 64];
+    /// let (padding, value, remainder) = u64::alloc_zeroed_with_padding(&mut buffer[1..]).unwrap();
+    /// assert_eq!(*value, 0);
+    /// assert_eq!(padding.len() + 8 + remainder.len(), 63);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn alloc_zeroed_with_padding(
+        mem: &mut [u8],
+    ) -> Result<(&mut [u8], &mut Self, &mut [u8]), AllocError> {
+        let (offset, _) = slice_layout::<Self>(mem, 1)?;
+        let (padding, rest) = mem.split_at_mut(offset);
+        // SAFETY: `slice_layout` placed one `Self` at `offset`, which is the start of `rest`
+        let (value, remainder) = unsafe { zeroed_slice_at::<Self>(rest, 0, 1) };
+
+        Ok((padding, &mut value[0], remainder))
+    }
+
     /// Allocates the largest possible slice of zero-initialized `T` values from a byte buffer
     ///
     /// This method attempts to allocate a slice of `T` values from the provided byte buffer,
@@ -159,7 +1165,7 @@ pub unsafe trait AllocZeroed: Sized {
     /// be valid for type `T`. This is guaranteed by the [`AllocZeroed`] trait bound.
     ///
     /// # Behavior for Zero-Sized Types (ZSTs)
-    /// For zero-sized types, this returns a slice of length [`usize::MAX`] since ZSTs require
+    /// For zero-sized types, this returns a slice of length [`isize::MAX`] since ZSTs require
     /// no storage and can be created in unlimited quantities from any aligned pointer.
     ///
     /// # Errors
@@ -167,6 +1173,10 @@ pub unsafe trait AllocZeroed: Sized {
     /// - The buffer cannot be aligned to `T`'s alignment requirements
     /// - The available space after alignment is smaller than the size of one `T`
     ///
+    /// In debug builds with the `log` feature, a buffer longer than `isize::MAX / 2` bytes is
+    /// reported with a warning, as it usually comes from a bug in how the buffer was built.
+    /// The allocation proceeds regardless.
+    ///
     /// # Examples
     /// ```
     /// # use alloc_zeroed::AllocZeroed;
@@ -178,24 +1188,266 @@ pub unsafe trait AllocZeroed: Sized {
     ///
     /// [`alloc_zeroed`]: AllocZeroed::alloc_zeroed
     fn alloc_zeroed_slice(mem: &mut [u8]) -> Result<&mut [Self], AllocError> {
-        let size = size_of::<Self>();
-        let align = align_of::<Self>();
-        let mem_ptr = mem.as_mut_ptr();
-        let offset = mem_ptr.align_offset(align);
-        let available_bytes = mem.len().saturating_sub(offset);
-
-        // Calculate how many complete items we can fit
-        let count = if size == 0 {
-            usize::MAX
-        } else {
-            available_bytes / size
-        };
+        #[cfg(feature = "log")]
+        warn_if_oversized(mem.len());
+        let count = max_count::<Self>(mem)?;
 
         let (slice, _) = Self::alloc_zeroed_slice_with_remainder(mem, count)?;
 
         Ok(slice)
     }
 
+    /// Allocates a slice of `count` zero-initialized `Self` values, returning the slice, the
+    /// remainder and how many bytes were consumed as a [`SliceAlloc`].
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [`alloc_zeroed_slice_with_remainder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let result = u16::alloc_zeroed_slice_result(&mut buffer, 8).unwrap();
+    /// assert_eq!(result.slice, [0; 8]);
+    /// assert_eq!(result.bytes_used, result.padding + 16);
+    /// assert_eq!(result.remainder.len(), 64 - result.bytes_used);
+    /// ```
+    ///
+    /// [`alloc_zeroed_slice_with_remainder`]: AllocZeroed::alloc_zeroed_slice_with_remainder
+    fn alloc_zeroed_slice_result(
+        mem: &mut [u8],
+        count: usize,
+    ) -> Result<SliceAlloc<'_, Self>, AllocError> {
+        let (padding, total_bytes) = slice_layout::<Self>(mem, count)?;
+        // SAFETY: `slice_layout` placed `count` values of `Self` at `padding`
+        let (slice, remainder) = unsafe { zeroed_slice_at::<Self>(mem, padding, count) };
+
+        Ok(SliceAlloc {
+            slice,
+            remainder,
+            bytes_used: padding + total_bytes,
+            padding,
+        })
+    }
+
+    /// Allocates a slice of `count` zero-initialized `Self` values and returns an iterator over
+    /// the elements and their indices, together with the remainder.
+    ///
+    /// This is convenient for position-dependent initialization right after allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let (elements, _remainder) = u32::alloc_zeroed_slice_enumerate(&mut buffer, 8).unwrap();
+    /// for (i, element) in elements {
+    ///     *element = i as u32 * 10;
+    /// }
+    /// ```
+    fn alloc_zeroed_slice_enumerate<'a>(
+        mem: &'a mut [u8],
+        count: usize,
+    ) -> Result<(impl Iterator<Item = (usize, &'a mut Self)>, &'a mut [u8]), AllocError>
+    where
+        Self: 'a,
+    {
+        let (slice, remainder) = Self::alloc_zeroed_slice_with_remainder(mem, count)?;
+
+        Ok((slice.iter_mut().enumerate(), remainder))
+    }
+
+    /// Allocates a slice of `count` values, initializing each element with `f(index)`, and
+    /// returns it together with the remainder.
+    ///
+    /// The slots are zeroed first, so if `f` panics part-way through, the elements not yet
+    /// visited still hold valid zeroed values. The zeroed values are overwritten without
+    /// running their destructor.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as
+    /// [`alloc_zeroed_slice_with_remainder`](AllocZeroed::alloc_zeroed_slice_with_remainder).
+    /// `f` is not called when the allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let (squares, _remainder) = u32::alloc_zeroed_slice_init(&mut buffer, 4, |i| (i * i) as u32).unwrap();
+    /// assert_eq!(squares, &[0, 1, 4, 9]);
+    /// ```
+    fn alloc_zeroed_slice_init<F: FnMut(usize) -> Self>(
+        mem: &mut [u8],
+        count: usize,
+        mut f: F,
+    ) -> Result<(&mut [Self], &mut [u8]), AllocError> {
+        let (slice, remainder) = Self::alloc_zeroed_slice_with_remainder(mem, count)?;
+
+        for (i, slot) in slice.iter_mut().enumerate() {
+            // SAFETY: `slot` is a valid, aligned `&mut Self`; the zeroed value it holds is
+            // overwritten without being dropped.
+            unsafe { core::ptr::write(slot, f(i)) };
+        }
+
+        Ok((slice, remainder))
+    }
+
+    /// Carves one zeroed byte record per entry in `record_sizes` out of `mem`, storing them
+    /// in `records`.
+    ///
+    /// Each record starts at an address aligned to `Self`, so it can later be reinterpreted
+    /// as (or hold) values of `Self`. The records are stored in order and the unused
+    /// remainder of the buffer is returned. Taking the output slice from the caller keeps
+    /// this usable without an allocator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::BufferTooSmall` if the records (including alignment padding
+    /// between them) do not fit in `mem`, or `AllocError::AlignmentFailed` if a record cannot
+    /// be aligned. `records` is left unchanged on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `records` and `record_sizes` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xFFu8; 64];
+    /// let mut records: [&mut [u8]; 2] = Default::default();
+    /// let _remainder = u32::alloc_zeroed_records(&mut buffer, &[3, 8], &mut records).unwrap();
+    /// assert_eq!(records[0].len(), 3);
+    /// assert_eq!(records[1].as_ptr() as usize % 4, 0);
+    /// ```
+    fn alloc_zeroed_records<'a>(
+        mem: &'a mut [u8],
+        record_sizes: &[usize],
+        records: &mut [&'a mut [u8]],
+    ) -> Result<&'a mut [u8], AllocError> {
+        assert_eq!(
+            records.len(),
+            record_sizes.len(),
+            "one output slot is needed per record"
+        );
+
+        let align = align_of::<Self>();
+
+        // Check that every record fits before handing any of the buffer out
+        let mut cursor: &[u8] = mem;
+        for &size in record_sizes {
+            let offset = region_layout(cursor, size, align)?;
+            cursor = &cursor[offset + size..];
+        }
+
+        let mut rest = mem;
+        for (slot, &size) in records.iter_mut().zip(record_sizes) {
+            let offset = align_padding(rest.as_ptr(), align);
+            let (record, remainder) = core::mem::take(&mut rest)[offset..].split_at_mut(size);
+
+            record.fill(0);
+            *slot = record;
+            rest = remainder;
+        }
+
+        Ok(rest)
+    }
+
+    /// Allocates as many zero-initialized `Self` values as fit in the buffer, but no fewer
+    /// than `min`, returning the slice and the remainder.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::BufferTooSmall` if fewer than `min` elements fit after alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let (slots, _remainder) = u8::alloc_zeroed_slice_at_least(&mut buffer, 16).unwrap();
+    /// assert_eq!(slots.len(), 64);
+    ///
+    /// assert!(u8::alloc_zeroed_slice_at_least(&mut buffer, 65).is_err());
+    /// ```
+    fn alloc_zeroed_slice_at_least(
+        mem: &mut [u8],
+        min: usize,
+    ) -> Result<(&mut [Self], &mut [u8]), AllocError> {
+        // Too few fit: allocating `min` reports the shortfall, or succeeds if `min` is 0
+        let count = fitting_count::<Self>(mem).max(min);
+
+        Self::alloc_zeroed_slice_with_remainder(mem, count)
+    }
+
+    /// Allocates the largest slice whose length is a power of two that fits in the buffer,
+    /// returning the remainder.
+    ///
+    /// This suits ring buffers and hash tables that require a power-of-two capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::BufferTooSmall` if not even one element fits after alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0u8; 1000];
+    /// let (ring, _remainder) = u64::alloc_zeroed_slice_pow2(&mut buffer).unwrap();
+    /// assert!(ring.len().is_power_of_two());
+    /// ```
+    fn alloc_zeroed_slice_pow2(mem: &mut [u8]) -> Result<(&mut [Self], &mut [u8]), AllocError> {
+        let max = max_count::<Self>(mem)?;
+
+        // The largest power of two not exceeding `max`
+        let count = 1 << (usize::BITS - 1 - max.leading_zeros());
+
+        Self::alloc_zeroed_slice_with_remainder(mem, count)
+    }
+
+    /// Allocates `count` zero-initialized `(Self, U)` pairs laid out contiguously, returning
+    /// the remainder.
+    ///
+    /// This is the array-of-structs counterpart of [`soa_alloc_zeroed!`](crate::soa_alloc_zeroed).
+    /// The pairs are Rust tuples, so the compiler picks their layout: the stride between
+    /// pairs is `size_of::<(Self, U)>()`, which rounds `size_of::<Self>() + size_of::<U>()`
+    /// plus any padding between them up to a multiple of the larger alignment of the two.
+    /// For `(u8, u32)` that is 8 bytes, of which 3 are padding.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`alloc_zeroed_slice_with_remainder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroed;
+    ///
+    /// let mut buffer = [0xFFu8; 128];
+    /// let (pairs, _remainder) = u8::alloc_zeroed_pairs::<u32>(&mut buffer, 4).unwrap();
+    /// assert_eq!(pairs, [(0, 0); 4]);
+    /// ```
+    ///
+    /// [`alloc_zeroed_slice_with_remainder`]: AllocZeroed::alloc_zeroed_slice_with_remainder
+    #[allow(clippy::type_complexity)]
+    fn alloc_zeroed_pairs<U: AllocZeroed>(
+        mem: &mut [u8],
+        count: usize,
+    ) -> Result<(&mut [(Self, U)], &mut [u8]), AllocError> {
+        <(Self, U)>::alloc_zeroed_slice_with_remainder(mem, count)
+    }
+
     /// Allocates a slice of zero-initialized `Self` values from the buffer, returning the remainder.
     ///
     /// This method allocates memory for multiple instances of `Self` within the given byte buffer,
@@ -220,6 +1472,7 @@ pub unsafe trait AllocZeroed: Sized {
     /// Returns `AllocError` in the following cases:
     /// * `AllocError::BufferTooSmall` - The buffer doesn't have enough space for all requested elements
     /// * `AllocError::AlignmentFailed` - The buffer cannot be aligned to the type's requirements
+    /// * `AllocError::InvalidLayout` - The total size of the slice exceeds `isize::MAX` bytes
     ///
     /// # Safety
     ///
@@ -283,9 +1536,10 @@ pub unsafe trait AllocZeroed: Sized {
     ///
     /// # Zero-Sized Types
     ///
-    /// For zero-sized types (ZSTs), this method always succeeds and returns a slice of length
-    /// `usize::MAX` along with the original buffer as remainder, as ZSTs don't require actual
-    /// memory allocation.
+    /// For zero-sized types (ZSTs), this method always succeeds and returns a slice of exactly
+    /// `count` elements (capped at `isize::MAX`) along with the original buffer as remainder,
+    /// as ZSTs don't require actual memory allocation. Likewise, a `count` of zero always
+    /// succeeds without consuming any of the buffer.
     ///
     /// # Performance Notes
     ///
@@ -303,56 +1557,11 @@ pub unsafe trait AllocZeroed: Sized {
         mem: &mut [u8],
         count: usize,
     ) -> Result<(&mut [Self], &mut [u8]), AllocError> {
-        use core::mem::{align_of, size_of};
+        // Zero-sized types and empty slices consume nothing, so they fit even where no
+        // element could be aligned
+        let (offset, _) = slice_layout::<Self>(mem, count)?;
 
-        let size = size_of::<Self>();
-        let align = align_of::<Self>();
-
-        // Handle zero-sized types
-        if size == 0 {
-            // For ZSTs, we can create as many as will fit in usize::MAX
-            let slice = unsafe {
-                core::slice::from_raw_parts_mut(
-                    core::ptr::NonNull::<Self>::dangling().as_ptr(),
-                    usize::MAX,
-                )
-            };
-            return Ok((slice, mem));
-        }
-
-        let mem_ptr = mem.as_mut_ptr();
-        let offset = mem_ptr.align_offset(align);
-
-        if offset == usize::MAX {
-            return Err(AllocError::builder(AllocErrorKind::AlignmentFailed {
-                required_alignment: align,
-                address: mem_ptr as usize,
-            })
-            .build());
-        }
-
-        let available_bytes = mem.len().saturating_sub(offset);
-        let total_bytes = size * count;
-        if available_bytes < total_bytes {
-            return Err(AllocError::builder(AllocErrorKind::BufferTooSmall {
-                required: total_bytes,
-                available: available_bytes,
-                alignment: align,
-            })
-            .build());
-        }
-
-        let (_before, after) = mem.split_at_mut(offset);
-        let (alloc_slice, remainder) = after.split_at_mut(total_bytes);
-
-        // Zero the memory
-        alloc_slice.fill(0);
-
-        // SAFETY: We've ensured the pointer is properly aligned and there's enough space
-        // The memory has been zeroed, which is valid for T (guaranteed by AllocZeroed trait bound)
-        unsafe {
-            let ptr = alloc_slice.as_mut_ptr() as *mut Self;
-            Ok((core::slice::from_raw_parts_mut(ptr, count), remainder))
-        }
+        // SAFETY: `slice_layout` placed `count` values of `Self` at `offset`
+        Ok(unsafe { zeroed_slice_at::<Self>(mem, offset, count) })
     }
 }
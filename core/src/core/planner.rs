@@ -0,0 +1,102 @@
+/// Predicts how much of a buffer remains after a sequence of allocations, without
+/// allocating.
+///
+/// Each entry is placed the way [`BufferAllocator`] would place it: at the next address
+/// aligned for the entry, directly after the previous one. Since the padding depends on
+/// where the buffer starts, plan against the buffer itself with [`for_buffer`], or use
+/// [`new`] for a buffer whose start is aligned for every entry.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::LayoutPlanner;
+///
+/// let plan = LayoutPlanner::new(64)
+///     .entry_for::<u8>(1)
+///     .entry_for::<u32>(5)
+///     .entry_for::<u64>(1);
+///
+/// // 1 byte, 3 bytes of padding, 20 bytes and 8 bytes
+/// assert_eq!(plan.leftover(), Ok(32));
+///
+/// let too_much = plan.entry_for::<u64>(8);
+/// assert_eq!(too_much.leftover(), Err(3));
+/// ```
+///
+/// [`BufferAllocator`]: crate::BufferAllocator
+/// [`for_buffer`]: LayoutPlanner::for_buffer
+/// [`new`]: LayoutPlanner::new
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutPlanner {
+    address: usize,
+    remaining: usize,
+    entries: usize,
+    failed_entry: Option<usize>,
+}
+
+impl LayoutPlanner {
+    /// Plans against a buffer of `len` bytes whose start is aligned for every entry.
+    pub fn new(len: usize) -> Self {
+        Self {
+            address: 0,
+            remaining: len,
+            entries: 0,
+            failed_entry: None,
+        }
+    }
+
+    /// Plans against `mem`, taking the padding its address requires into account.
+    pub fn for_buffer(mem: &[u8]) -> Self {
+        Self {
+            address: mem.as_ptr().addr(),
+            ..Self::new(mem.len())
+        }
+    }
+
+    /// Adds `count` elements of `size` bytes aligned to `align`.
+    ///
+    /// Entries after one that does not fit are ignored.
+    pub fn entry(mut self, size: usize, align: usize, count: usize) -> Self {
+        let index = self.entries;
+        self.entries += 1;
+
+        if self.failed_entry.is_some() || size == 0 || count == 0 {
+            return self;
+        }
+
+        let total_bytes = size.saturating_mul(count);
+        let padding = if align.is_power_of_two() {
+            self.address.wrapping_neg() & (align - 1)
+        } else {
+            usize::MAX
+        };
+
+        if total_bytes > isize::MAX as usize
+            || padding > self.remaining
+            || self.remaining - padding < total_bytes
+        {
+            self.failed_entry = Some(index);
+            return self;
+        }
+
+        let used = padding + total_bytes;
+        self.address = self.address.wrapping_add(used);
+        self.remaining -= used;
+
+        self
+    }
+
+    /// Adds `count` elements of `T`.
+    pub fn entry_for<T>(self, count: usize) -> Self {
+        self.entry(size_of::<T>(), align_of::<T>(), count)
+    }
+
+    /// Returns the number of bytes left after all entries, or the index of the first entry
+    /// that would not fit.
+    pub fn leftover(&self) -> Result<usize, usize> {
+        match self.failed_entry {
+            Some(index) => Err(index),
+            None => Ok(self.remaining),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+/// A zero-initialized `T` in a buffer that can give the whole buffer back once the value is
+/// no longer needed.
+///
+/// Returned by [`AllocZeroed::alloc_zeroed_reclaimable`]. The value is reached through
+/// `Deref` and `DerefMut`, and [`reclaim`] ends its use and returns the entire original
+/// buffer, alignment padding included, for another allocation. The value's destructor is
+/// not run.
+///
+/// Both the value and the reclaimed buffer are derived from the pointer of the original
+/// buffer, so reclaiming never widens a reference to the value.
+///
+/// [`AllocZeroed::alloc_zeroed_reclaimable`]: crate::AllocZeroed::alloc_zeroed_reclaimable
+/// [`reclaim`]: Reclaimable::reclaim
+#[derive(Debug)]
+pub struct Reclaimable<'a, T> {
+    base: NonNull<u8>,
+    len: usize,
+    offset: usize,
+    _marker: PhantomData<(&'a mut [u8], &'a mut T)>,
+}
+
+impl<'a, T> Reclaimable<'a, T> {
+    /// Wraps the `T` at `offset` bytes into the `len`-byte buffer at `base`.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to a buffer of `len` bytes borrowed exclusively for `'a`, holding
+    /// a valid `T` at `offset` unless `T` is zero-sized.
+    pub(crate) unsafe fn new(base: NonNull<u8>, len: usize, offset: usize) -> Self {
+        Self {
+            base,
+            len,
+            offset,
+            _marker: PhantomData,
+        }
+    }
+
+    fn value_ptr(&self) -> NonNull<T> {
+        if size_of::<T>() == 0 {
+            return NonNull::dangling();
+        }
+
+        // SAFETY: `offset` lies within the buffer, as guaranteed by `new`
+        unsafe { self.base.add(self.offset).cast() }
+    }
+
+    /// Ends the use of the value and returns the whole buffer it was allocated from.
+    pub fn reclaim(self) -> &'a mut [u8] {
+        // SAFETY: The buffer is borrowed exclusively for `'a`, and every reference to the
+        // value borrowed `self`, which is consumed here
+        unsafe { core::slice::from_raw_parts_mut(self.base.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Deref for Reclaimable<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The pointer is valid for a `T`, and shared for as long as `self` is
+        unsafe { self.value_ptr().as_ref() }
+    }
+}
+
+impl<T> DerefMut for Reclaimable<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The pointer is valid for a `T`, and exclusive for as long as `self` is
+        unsafe { self.value_ptr().as_mut() }
+    }
+}
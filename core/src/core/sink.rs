@@ -0,0 +1,105 @@
+use core::fmt;
+
+use crate::{AllocError, AllocZeroed};
+
+/// A write cursor over a zeroed byte region, for filling a record incrementally.
+///
+/// Text is written through [`fmt::Write`] and binary data through [`write_bytes`]. Writes
+/// that do not fit are rejected whole, so the region never holds a truncated write, and
+/// bytes past the cursor keep their zero value. This includes `write!`, which emits its
+/// output in several pieces: when one does not fit, the pieces already written are zeroed
+/// again and the cursor moves back to where the `write!` started.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use alloc_zeroed::{AllocZeroed, ZeroSink};
+///
+/// let mut buffer = [0xFFu8; 32];
+/// let record = <[u8; 16]>::alloc_zeroed(&mut buffer).unwrap();
+///
+/// let mut sink = ZeroSink::new(record);
+/// write!(sink, "v{}:", 2).unwrap();
+/// sink.write_bytes(&[0xAB, 0xCD]).unwrap();
+///
+/// assert_eq!(&record[..5], b"v2:\xAB\xCD");
+/// assert_eq!(record[5..], [0; 11]);
+/// ```
+///
+/// [`write_bytes`]: ZeroSink::write_bytes
+#[derive(Debug)]
+pub struct ZeroSink<'a> {
+    bytes: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> ZeroSink<'a> {
+    /// Creates a sink writing from the start of `bytes`.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Creates a sink writing over the bytes of `value`.
+    ///
+    /// # Safety
+    ///
+    /// `AllocZeroed` only promises that the all-zero pattern is a valid `T`. The caller must
+    /// guarantee that the bytes written keep `value` a valid `T`, and that `T` has no padding
+    /// bytes, which may not be read as `u8`.
+    pub unsafe fn from_value<T: AllocZeroed>(value: &'a mut T) -> Self {
+        // SAFETY: `value` is valid for `size_of::<T>()` bytes for `'a`; the rest is upheld by
+        // the caller
+        let bytes =
+            unsafe { core::slice::from_raw_parts_mut(value as *mut T as *mut u8, size_of::<T>()) };
+
+        Self::new(bytes)
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes that can still be written.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// Writes `src` at the cursor and advances past it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::BufferTooSmall` without writing anything if `src` is longer than
+    /// [`remaining`](ZeroSink::remaining).
+    pub fn write_bytes(&mut self, src: &[u8]) -> Result<(), AllocError> {
+        let remaining = self.remaining();
+
+        if src.len() > remaining {
+            return Err(AllocError::buffer_too_small(src.len(), remaining, 1)
+                .with_context("write past the end of a ZeroSink")
+                .build());
+        }
+
+        self.bytes[self.position..self.position + src.len()].copy_from_slice(src);
+        self.position += src.len();
+
+        Ok(())
+    }
+}
+
+impl fmt::Write for ZeroSink<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_bytes(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        let start = self.position;
+
+        fmt::write(self, args).inspect_err(|_| {
+            // Roll back the pieces that did fit
+            self.bytes[start..self.position].fill(0);
+            self.position = start;
+        })
+    }
+}
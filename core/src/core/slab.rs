@@ -0,0 +1,120 @@
+use crate::{AllocError, AllocZeroed};
+
+/// A handle to an occupied slot of a [`Slab`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlabKey(usize);
+
+impl SlabKey {
+    /// Returns the index of the slot in the slab.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A fixed-capacity slab of zero-initialized `T` values carved out of a buffer.
+///
+/// [`insert`] hands out the next free slot, already zeroed, and returns a [`SlabKey`] for it.
+/// [`remove`] zeroes the slot again so that it is clean when reused. Like other buffer
+/// allocations, values are overwritten without running their destructors.
+///
+/// Free slots are found by a linear scan, which suits the small, fixed capacities this is
+/// meant for.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::Slab;
+///
+/// let mut buffer = [0u8; 256];
+/// let mut slab = Slab::<u64>::new(&mut buffer, 8).unwrap();
+///
+/// let key = slab.insert().unwrap();
+/// *slab.get_mut(key).unwrap() = 42;
+/// assert_eq!(slab.get(key), Some(&42));
+///
+/// slab.remove(key);
+/// assert_eq!(slab.get(key), None);
+/// ```
+///
+/// [`insert`]: Slab::insert
+/// [`remove`]: Slab::remove
+#[derive(Debug)]
+pub struct Slab<'a, T> {
+    slots: &'a mut [T],
+    occupied: &'a mut [bool],
+    len: usize,
+}
+
+impl<'a, T: AllocZeroed> Slab<'a, T> {
+    /// Creates a slab of `capacity` slots in `mem`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`AllocZeroed::alloc_zeroed_slice_with_remainder`] when
+    /// `mem` cannot hold the slots and their occupancy flags.
+    pub fn new(mem: &'a mut [u8], capacity: usize) -> Result<Self, AllocError> {
+        let (slots, rest) = T::alloc_zeroed_slice_with_remainder(mem, capacity)?;
+        let (occupied, _) = bool::alloc_zeroed_slice_with_remainder(rest, capacity)?;
+
+        Ok(Self {
+            slots: &mut slots[..capacity],
+            occupied,
+            len: 0,
+        })
+    }
+
+    /// Returns the number of slots.
+    pub fn capacity(&self) -> usize {
+        self.occupied.len()
+    }
+
+    /// Returns the number of occupied slots.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no slot is occupied.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Occupies the first free slot, which holds a zeroed `T`, or returns `None` if the
+    /// slab is full.
+    pub fn insert(&mut self) -> Option<SlabKey> {
+        let index = self.occupied.iter().position(|&occupied| !occupied)?;
+
+        self.occupied[index] = true;
+        self.len += 1;
+
+        Some(SlabKey(index))
+    }
+
+    /// Returns the value in the slot of `key`, or `None` if the slot was removed.
+    pub fn get(&self, key: SlabKey) -> Option<&T> {
+        self.is_occupied(key).then(|| &self.slots[key.0])
+    }
+
+    /// Returns the value in the slot of `key` mutably, or `None` if the slot was removed.
+    pub fn get_mut(&mut self, key: SlabKey) -> Option<&mut T> {
+        self.is_occupied(key).then(|| &mut self.slots[key.0])
+    }
+
+    /// Frees the slot of `key` and zeroes it for reuse. Returns `false` if the slot was not
+    /// occupied.
+    pub fn remove(&mut self, key: SlabKey) -> bool {
+        if !self.is_occupied(key) {
+            return false;
+        }
+
+        // SAFETY: The slot is a valid `T`, and all zeros is a valid `T` per the trait contract
+        unsafe { core::ptr::write_bytes(&mut self.slots[key.0] as *mut T, 0, 1) };
+        self.occupied[key.0] = false;
+        self.len -= 1;
+
+        true
+    }
+
+    fn is_occupied(&self, key: SlabKey) -> bool {
+        self.occupied.get(key.0).copied().unwrap_or(false)
+    }
+}
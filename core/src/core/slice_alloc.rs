@@ -0,0 +1,17 @@
+/// The result of [`AllocZeroed::alloc_zeroed_slice_result`].
+///
+/// Bundles the allocated slice with the rest of the buffer and how the consumed bytes were
+/// spent, which is handier than a tuple when logging or checking layouts.
+///
+/// [`AllocZeroed::alloc_zeroed_slice_result`]: crate::AllocZeroed::alloc_zeroed_slice_result
+#[derive(Debug)]
+pub struct SliceAlloc<'a, T> {
+    /// The zero-initialized elements.
+    pub slice: &'a mut [T],
+    /// The part of the buffer after the slice.
+    pub remainder: &'a mut [u8],
+    /// The number of bytes consumed from the buffer, including `padding`.
+    pub bytes_used: usize,
+    /// The number of bytes skipped before the slice to align it.
+    pub padding: usize,
+}
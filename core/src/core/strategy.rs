@@ -0,0 +1,38 @@
+use crate::{AllocError, AllocZeroed, BufferAllocator};
+
+/// A source of zero-initialized allocations.
+///
+/// Generic code can be written once against this trait and run over a borrowed buffer
+/// ([`BufferStrategy`]) in `no_std` environments or over the global allocator
+/// (`HeapStrategy`, requires the `std` feature).
+///
+/// References returned by a strategy stay valid for `'a`, so several allocations can be
+/// alive at the same time.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::{AllocError, AllocStrategy, BufferStrategy};
+///
+/// fn make_pair<'a, S: AllocStrategy<'a>>(
+///     strategy: &mut S,
+/// ) -> Result<(&'a mut u32, &'a mut u64), AllocError> {
+///     Ok((strategy.allocate_zeroed()?, strategy.allocate_zeroed()?))
+/// }
+///
+/// let mut buffer = [0u8; 64];
+/// let mut strategy = BufferStrategy::new(&mut buffer);
+/// let (a, b) = make_pair(&mut strategy).unwrap();
+/// assert_eq!((*a, *b), (0, 0));
+/// ```
+pub trait AllocStrategy<'a> {
+    /// Allocates a zero-initialized `T`.
+    fn allocate_zeroed<T: AllocZeroed + 'a>(&mut self) -> Result<&'a mut T, AllocError>;
+}
+
+/// An [`AllocStrategy`] that carves allocations sequentially out of a borrowed buffer.
+///
+/// This is the [`BufferAllocator`] cursor under the name generic strategy code uses. A
+/// failed allocation leaves the buffer untouched, so a smaller allocation may still
+/// succeed afterwards.
+pub type BufferStrategy<'a> = BufferAllocator<'a>;
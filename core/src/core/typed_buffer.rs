@@ -0,0 +1,79 @@
+use core::marker::PhantomData;
+
+use crate::AllocZeroed;
+
+/// An inline byte buffer sized so that a `T` always fits, whatever its address.
+///
+/// `N` must be at least `size_of::<T>() + align_of::<T>() - 1`, which leaves room for the
+/// worst-case alignment padding; this is checked at compile time. The [`typed_buffer!`]
+/// macro computes `N` for a concrete type, which works on stable Rust without
+/// `generic_const_exprs`.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::{AllocZeroed, typed_buffer};
+///
+/// #[derive(AllocZeroed)]
+/// struct Registers {
+///     control: u32,
+///     data: [u64; 8],
+/// }
+///
+/// let mut buffer = typed_buffer!(Registers);
+/// let registers = buffer.alloc();
+/// assert_eq!(registers.data, [0; 8]);
+/// ```
+#[derive(Debug)]
+pub struct TypedBuffer<T, const N: usize> {
+    bytes: [u8; N],
+    _marker: PhantomData<T>,
+}
+
+impl<T: AllocZeroed, const N: usize> TypedBuffer<T, N> {
+    /// Creates a zeroed buffer.
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                N >= size_of::<T>() + align_of::<T>() - 1,
+                "TypedBuffer is too small for its type"
+            )
+        };
+
+        Self {
+            bytes: [0; N],
+            _marker: PhantomData,
+        }
+    }
+
+    /// Zero-initializes a `T` in the buffer.
+    ///
+    /// This cannot fail, as the buffer is large enough for `T` at any alignment.
+    pub fn alloc(&mut self) -> &mut T {
+        T::alloc_zeroed(&mut self.bytes).expect("TypedBuffer is sized to fit its type")
+    }
+}
+
+impl<T: AllocZeroed, const N: usize> Default for TypedBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a [`TypedBuffer`] with exactly enough room for the given type.
+///
+/// ```
+/// use alloc_zeroed::typed_buffer;
+///
+/// let mut buffer = typed_buffer!([u32; 4]);
+/// assert_eq!(*buffer.alloc(), [0; 4]);
+/// ```
+#[macro_export]
+macro_rules! typed_buffer {
+    ($ty:ty) => {
+        $crate::TypedBuffer::<
+            $ty,
+            { ::core::mem::size_of::<$ty>() + ::core::mem::align_of::<$ty>() - 1 },
+        >::new()
+    };
+}
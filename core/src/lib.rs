@@ -15,6 +15,12 @@
 //! - **Detailed error reporting**: Rich error information for allocation failures
 //! - **Derive macro**: Automatic implementation for structs with `#[derive(AllocZeroed)]`
 //! - **Standard library integration**: Optional `std` feature for `Box`-based allocation
+//! - **Logging**: Optional `log` feature that emits a `log::warn!` for every constructed `AllocError`
+//! - **Stable deref**: Optional `stable_deref_trait` feature implementing `StableDeref` for `ZeroedBox`
+//! - **Generic arrays**: Optional `generic-array` feature implementing `AllocZeroed` for `GenericArray<T, N>`
+//! - **Memory maps**: Optional `mmap` feature adding `MmapBuffer`, which skips zeroing regions known to be zero
+//! - **Minimal errors**: Optional `minimal-errors` feature reducing `AllocError` to its kind, dropping type names, locations and contexts
+//! - **Diagnostics**: Optional `diag` feature recording recent allocation attempts per thread, readable with `diag::dump()`
 //!
 //! # Usage
 //!
@@ -91,15 +97,33 @@
 
 #![no_std]
 
+// Lets code generated by the derive macro name `::alloc_zeroed` inside this crate too
+extern crate self as alloc_zeroed;
+
 mod core;
 
-pub use crate::core::{AllocError, AllocErrorKind, AllocZeroed};
+pub use crate::core::{
+    AlignedBuffer, Alignment, AllocError, AllocErrorKind, AllocErrorTag, AllocFilled,
+    AllocStrategy, AllocZeroed, BOXED_METHOD_ERRORS, BUFFER_METHOD_ERRORS, BufferAllocator,
+    BufferStrategy, DualView, LayoutPlanner, LenPrefixed, MAX_CONTEXTS, Reclaimable, Slab, SlabKey,
+    SliceAlloc, SupportedAlignment, TxAllocator, TypedBuffer, ZeroSink, alloc_zeroed_bytes,
+    is_buffer_zeroed,
+};
 
 #[cfg(feature = "std")]
 mod std;
 
 #[cfg(feature = "std")]
-pub use crate::std::AllocZeroedBoxed;
+pub use crate::std::{
+    AllocZeroedBoxed, BoxedAllocator, DropGuard, HeapStrategy, ZeroBuf, ZeroedBox, ZeroedPool,
+    alloc_zeroed_chunks, configure_scratch, with_scratch,
+};
+
+#[cfg(feature = "mmap")]
+pub use crate::std::MmapBuffer;
+
+#[cfg(feature = "diag")]
+pub use crate::std::diag;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,79 @@
+use super::std;
+use std::alloc::{Layout, alloc_zeroed};
+use std::boxed::Box;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::{AllocError, AllocErrorKind, AllocZeroed, alloc_err};
+
+/// Allocates zero-initialized boxes of one type, with the layout checked once up front.
+///
+/// This is meant for hot loops creating many small objects of the same type, where
+/// [`alloc_zeroed_boxed`] would validate the layout on every call. Zero-sized types are
+/// handled without touching the allocator.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::BoxedAllocator;
+///
+/// let allocator = BoxedAllocator::<[u32; 16]>::new().unwrap();
+/// let nodes: Vec<_> = (0..8).map(|_| allocator.alloc().unwrap()).collect();
+/// assert!(nodes.iter().all(|node| **node == [0; 16]));
+/// ```
+///
+/// [`alloc_zeroed_boxed`]: crate::AllocZeroedBoxed::alloc_zeroed_boxed
+#[derive(Debug, Clone, Copy)]
+pub struct BoxedAllocator<T> {
+    layout: Layout,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: AllocZeroed> BoxedAllocator<T> {
+    /// Creates an allocator for `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::InvalidLayout` if `T` is larger than `isize::MAX` bytes.
+    pub fn new() -> Result<Self, AllocError> {
+        let layout = Layout::new::<T>();
+        crate::core::check_layout(layout.size(), layout.align())?;
+
+        Ok(Self {
+            layout,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the layout used for every allocation.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Allocates a zero-initialized `T` on the heap.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::OutOfMemory` if the global allocator fails.
+    pub fn alloc(&self) -> Result<Box<T>, AllocError> {
+        if self.layout.size() == 0 {
+            // SAFETY: A dangling pointer is valid for a zero-sized `T`
+            return Ok(unsafe { Box::from_raw(NonNull::<T>::dangling().as_ptr()) });
+        }
+
+        // SAFETY: The layout has a non-zero size
+        let ptr = unsafe { alloc_zeroed(self.layout) };
+        if ptr.is_null() {
+            return Err(alloc_err!(AllocErrorKind::OutOfMemory {
+                required: self.layout.size(),
+                alignment: self.layout.align(),
+            })
+            .with_type_name(std::any::type_name::<T>())
+            .build());
+        }
+
+        // SAFETY: `ptr` was allocated by the global allocator with the layout of `T`, and
+        // all-zero bytes are a valid `T`
+        Ok(unsafe { Box::from_raw(ptr.cast::<T>()) })
+    }
+}
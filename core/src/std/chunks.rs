@@ -0,0 +1,62 @@
+use super::std;
+use std::vec::Vec;
+
+use crate::{AllocError, AllocZeroed};
+
+/// Allocates as many zero-initialized `T` values as fit in `mem` and splits them into
+/// `chunks` consecutive subslices, e.g. one per worker thread.
+///
+/// The elements are spread as evenly as possible: the first `len % chunks` chunks hold
+/// one element more than the others. With fewer elements than chunks, the trailing
+/// chunks are empty, and with `chunks == 0` nothing is allocated. The chunks are
+/// returned with the remainder of the buffer.
+///
+/// # Errors
+///
+/// Returns `AllocError::BufferTooSmall` if not even one `T` fits in `mem`. The list of
+/// chunks is allocated on the heap before the buffer is touched: if it would exceed
+/// `isize::MAX` bytes, `AllocError::InvalidLayout` is returned, and if the system
+/// allocator cannot provide it, `AllocError::OutOfMemory`.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::alloc_zeroed_chunks;
+///
+/// let mut buffer = [0xFFu8; 10];
+/// let (chunks, _remainder) = alloc_zeroed_chunks::<u8>(&mut buffer, 3).unwrap();
+/// let lengths: Vec<_> = chunks.iter().map(|chunk| chunk.len()).collect();
+/// assert_eq!(lengths, [4, 3, 3]);
+/// ```
+pub fn alloc_zeroed_chunks<T: AllocZeroed>(
+    mem: &mut [u8],
+    chunks: usize,
+) -> Result<(Vec<&mut [T]>, &mut [u8]), AllocError> {
+    if chunks == 0 {
+        return Ok((Vec::new(), mem));
+    }
+
+    // `chunks` is caller-controlled, so reserve fallibly instead of aborting
+    let size = size_of::<&mut [T]>().saturating_mul(chunks);
+    let alignment = align_of::<&mut [T]>();
+    crate::core::check_layout(size, alignment)?;
+    let mut parts = Vec::new();
+    if parts.try_reserve_exact(chunks).is_err() {
+        return Err(AllocError::out_of_memory(size, alignment)
+            .with_type_name(std::any::type_name::<T>())
+            .build());
+    }
+
+    let count = crate::core::max_count::<T>(mem)?;
+    let (mut rest, remainder) = T::alloc_zeroed_slice_with_remainder(mem, count)?;
+    let (base_len, longer) = (count / chunks, count % chunks);
+
+    for i in 0..chunks {
+        let len = base_len + usize::from(i < longer);
+        let (chunk, tail) = std::mem::take(&mut rest).split_at_mut(len);
+        parts.push(chunk);
+        rest = tail;
+    }
+
+    Ok((parts, remainder))
+}
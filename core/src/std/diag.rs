@@ -0,0 +1,80 @@
+//! A per-thread log of recent allocation attempts, for post-mortem debugging.
+//!
+//! With the `diag` feature enabled, every attempt to allocate typed values from a buffer
+//! is recorded in a ring buffer local to the calling thread. This covers the
+//! [`AllocZeroed`] and [`AllocFilled`] methods as well as [`BufferAllocator`] and the
+//! types built on them, whether the attempt fails before or after alignment. Plain byte
+//! carving, such as [`alloc_zeroed_bytes`], is not recorded. Once the log holds
+//! [`CAPACITY`] records, the oldest one is dropped for each new attempt. Without the
+//! feature, no recording code is compiled in.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloc_zeroed::{AllocZeroed, diag};
+//!
+//! diag::clear();
+//!
+//! let mut buffer = [0u8; 16];
+//! let _ = u32::alloc_zeroed(&mut buffer);
+//! let _ = u64::alloc_zeroed_slice_with_remainder(&mut buffer, 4);
+//!
+//! let log = diag::dump();
+//! assert_eq!(log.len(), 2);
+//! assert!(log[0].success);
+//! assert!(!log[1].success);
+//! ```
+//!
+//! [`AllocZeroed`]: crate::AllocZeroed
+//! [`AllocFilled`]: crate::AllocFilled
+//! [`BufferAllocator`]: crate::BufferAllocator
+//! [`alloc_zeroed_bytes`]: crate::alloc_zeroed_bytes
+
+use super::std;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::vec::Vec;
+
+/// The number of records kept per thread.
+pub const CAPACITY: usize = 64;
+
+std::thread_local! {
+    static LOG: RefCell<VecDeque<DiagRecord>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// A single allocation attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagRecord {
+    /// The name of the allocated type, as given by `core::any::type_name`.
+    pub type_name: &'static str,
+    /// The requested size in bytes, saturating at `usize::MAX`.
+    pub size: usize,
+    /// The alignment padding between the start of the buffer and the allocation.
+    pub offset: usize,
+    /// Whether the allocation succeeded.
+    pub success: bool,
+}
+
+/// Returns the current thread's recorded attempts, oldest first.
+pub fn dump() -> Vec<DiagRecord> {
+    LOG.with(|log| log.borrow().iter().copied().collect())
+}
+
+/// Discards the current thread's recorded attempts.
+pub fn clear() {
+    LOG.with(|log| log.borrow_mut().clear());
+}
+
+/// Appends a record to the current thread's log, dropping the oldest one when full.
+pub(crate) fn record(record: DiagRecord) {
+    // Recording is best effort, a log being read or torn down is skipped
+    let _ = LOG.try_with(|log| {
+        let Ok(mut log) = log.try_borrow_mut() else {
+            return;
+        };
+        if log.len() == CAPACITY {
+            log.pop_front();
+        }
+        log.push_back(record);
+    });
+}
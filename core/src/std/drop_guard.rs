@@ -0,0 +1,104 @@
+use super::std;
+use std::cell::RefCell;
+use std::ptr::NonNull;
+use std::vec::Vec;
+
+use crate::{AllocError, AllocZeroed, BufferAllocator};
+
+/// A buffer arena that runs the destructors of its allocations when dropped.
+///
+/// Values allocated from a plain buffer are never dropped. `DropGuard` records every
+/// allocation whose type needs dropping and runs its destructor, in reverse allocation order,
+/// when the guard goes out of scope. Allocations borrow the guard, so none can be used after
+/// their destructor ran.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::{AllocZeroed, DropGuard};
+///
+/// #[derive(AllocZeroed)]
+/// struct Handle {
+///     fd: i32,
+/// }
+///
+/// impl Drop for Handle {
+///     fn drop(&mut self) {
+///         // A zeroed handle owns nothing, so this is a no-op until `fd` is set
+///     }
+/// }
+///
+/// let mut buffer = [0u8; 64];
+/// let guard = DropGuard::new(&mut buffer);
+/// let handle = guard.alloc::<Handle>().unwrap();
+/// handle.fd = 3;
+/// drop(guard); // runs `Handle::drop`
+/// ```
+pub struct DropGuard<'a> {
+    allocator: RefCell<BufferAllocator<'a>>,
+    drops: RefCell<Vec<PendingDrop>>,
+}
+
+/// A recorded allocation and the function that drops it.
+type PendingDrop = (NonNull<u8>, unsafe fn(NonNull<u8>));
+
+/// Drops the `T` at `ptr` in place.
+///
+/// # Safety
+///
+/// `ptr` must point to a live `T` that is not used afterwards.
+unsafe fn drop_value<T>(ptr: NonNull<u8>) {
+    // SAFETY: Upheld by the caller
+    unsafe { ptr.cast::<T>().drop_in_place() };
+}
+
+impl<'a> DropGuard<'a> {
+    /// Creates a guard allocating from `mem`.
+    pub fn new(mem: &'a mut [u8]) -> Self {
+        Self {
+            allocator: RefCell::new(BufferAllocator::new(mem)),
+            drops: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Allocates a zero-initialized `T` whose destructor runs when the guard is dropped.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: AllocZeroed + 'a>(&self) -> Result<&mut T, AllocError> {
+        let ptr = NonNull::from(self.allocator.borrow_mut().alloc::<T>()?);
+
+        if std::mem::needs_drop::<T>() {
+            self.drops
+                .borrow_mut()
+                .push((ptr.cast(), drop_value::<T> as unsafe fn(NonNull<u8>)));
+        }
+
+        // SAFETY: The allocation is exclusive to this call and lives as long as the buffer.
+        // The reference is derived from the recorded pointer, so it stays valid until the
+        // guard drops the value, which cannot happen while `self` is borrowed.
+        Ok(unsafe { &mut *ptr.as_ptr() })
+    }
+
+    /// Returns the number of allocations whose destructor will run.
+    pub fn pending_drops(&self) -> usize {
+        self.drops.borrow().len()
+    }
+}
+
+impl Drop for DropGuard<'_> {
+    fn drop(&mut self) {
+        for (ptr, drop) in self.drops.get_mut().drain(..).rev() {
+            // SAFETY: Each entry was recorded for a live value of the matching type, and no
+            // reference to it remains since `&mut self` is exclusive
+            unsafe { drop(ptr) };
+        }
+    }
+}
+
+impl std::fmt::Debug for DropGuard<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DropGuard")
+            .field("allocator", &self.allocator)
+            .field("pending_drops", &self.pending_drops())
+            .finish()
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{AllocError, AllocZeroed, BufferAllocator};
+
+/// An allocation cursor over a memory-mapped region that skips zeroing when the region is
+/// known to be zero.
+///
+/// Freshly mapped anonymous memory is already zero, so clearing it again only costs time
+/// and faults in pages early. File-backed mappings hold the file contents and must be
+/// zeroed. [`new`] makes no assumption and zeroes every allocation; [`new_zeroed`] trusts
+/// the caller that the region is all zeros and never writes to it.
+///
+/// Allocations are carved front to back by a [`BufferAllocator`], and a failed
+/// allocation leaves the cursor where it was.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::MmapBuffer;
+///
+/// // Stands in for the `&mut [u8]` of a file-backed mapping
+/// let mut mapping = [0xFFu8; 64];
+/// let mut region = MmapBuffer::new(&mut mapping);
+///
+/// let header = region.alloc::<u32>().unwrap();
+/// assert_eq!(*header, 0);
+/// ```
+///
+/// [`new`]: MmapBuffer::new
+/// [`new_zeroed`]: MmapBuffer::new_zeroed
+/// [`BufferAllocator`]: crate::BufferAllocator
+#[derive(Debug)]
+pub struct MmapBuffer<'a> {
+    cursor: BufferAllocator<'a>,
+    known_zero: bool,
+}
+
+impl<'a> MmapBuffer<'a> {
+    /// Wraps a region of unknown contents. Every allocation is zeroed.
+    pub fn new(mem: &'a mut [u8]) -> Self {
+        Self {
+            cursor: BufferAllocator::new(mem),
+            known_zero: false,
+        }
+    }
+
+    /// Wraps a region that is known to be all zeros, such as a fresh anonymous mapping.
+    /// Allocations are handed out without writing to the region.
+    ///
+    /// # Safety
+    ///
+    /// Every byte of `mem` must be zero. Otherwise allocated values may hold bit patterns
+    /// that are invalid for their type.
+    pub unsafe fn new_zeroed(mem: &'a mut [u8]) -> Self {
+        Self {
+            cursor: BufferAllocator::new(mem),
+            known_zero: true,
+        }
+    }
+
+    /// Returns `true` if allocations skip zeroing because the region is known to be zero.
+    pub fn is_known_zero(&self) -> bool {
+        self.known_zero
+    }
+
+    /// Returns the number of bytes not yet allocated.
+    pub fn remaining(&self) -> usize {
+        self.cursor.remaining()
+    }
+
+    /// Allocates a zero-initialized `T`.
+    pub fn alloc<T: AllocZeroed>(&mut self) -> Result<&'a mut T, AllocError> {
+        let slice = self.alloc_slice::<T>(1)?;
+
+        Ok(&mut slice[0])
+    }
+
+    /// Allocates a slice of `count` zero-initialized `T` values.
+    pub fn alloc_slice<T: AllocZeroed>(&mut self, count: usize) -> Result<&'a mut [T], AllocError> {
+        if size_of::<T>() == 0 || count == 0 {
+            // Zero-sized and empty slices take no bytes, so nothing needs zeroing
+            let (slice, _) = T::alloc_zeroed_slice_with_remainder(&mut [], count)?;
+            return Ok(slice);
+        }
+
+        let region = self.cursor.take_region::<T>(count)?;
+
+        if !self.known_zero {
+            region.fill(0);
+        }
+
+        // SAFETY: `take_region` aligned `region` for `T` and sized it for `count` values.
+        // Its bytes are zero, either just written or guaranteed by the caller of
+        // `new_zeroed`, which is a valid `T` per the trait contract.
+        Ok(unsafe { core::slice::from_raw_parts_mut(region.as_mut_ptr().cast::<T>(), count) })
+    }
+}
@@ -1,6 +1,29 @@
 extern crate std;
 
+mod boxed_allocator;
+mod chunks;
+#[cfg(feature = "diag")]
+pub mod diag;
+mod drop_guard;
 mod error;
+#[cfg(feature = "mmap")]
+mod mmap_buffer;
+mod scratch;
+mod strategy;
+mod zero_buf;
+mod zeroed_box;
+mod zeroed_pool;
+
+pub use boxed_allocator::BoxedAllocator;
+pub use chunks::alloc_zeroed_chunks;
+pub use drop_guard::DropGuard;
+#[cfg(feature = "mmap")]
+pub use mmap_buffer::MmapBuffer;
+pub use scratch::{configure_scratch, with_scratch};
+pub use strategy::HeapStrategy;
+pub use zero_buf::ZeroBuf;
+pub use zeroed_box::ZeroedBox;
+pub use zeroed_pool::ZeroedPool;
 
 use crate::{AllocError, AllocErrorKind, AllocZeroed, alloc_err};
 use std::boxed::Box;
@@ -16,7 +39,7 @@ pub trait AllocZeroedBoxed: crate::AllocZeroed {
     ///
     /// * `Ok(Box<Self>)` - A box containing the zero-initialized object if allocation succeeds.
     /// * `Err(AllocError)` - An error describing why allocation failed (out of memory
-    ///                       or invalid layout).
+    ///   or invalid layout).
     ///
     /// # Errors
     ///
@@ -59,6 +82,8 @@ pub trait AllocZeroedBoxed: crate::AllocZeroed {
         use std::alloc::{Layout, alloc_zeroed};
 
         let layout = Layout::new::<Self>();
+        crate::core::check_layout(layout.size(), layout.align())?;
+
         if std::mem::size_of::<Self>() == 0 {
             // For zero-sized types, we can use a dangling pointer
             let dangling_ptr = std::ptr::NonNull::<Self>::dangling().as_ptr();
@@ -91,6 +116,391 @@ pub trait AllocZeroedBoxed: crate::AllocZeroed {
             Ok(Box::from_raw(obj_ptr))
         }
     }
+
+    /// Allocates a zero-initialized `Self` on the heap like [`alloc_zeroed_boxed`], then
+    /// touches every page of it so that later accesses do not fault.
+    ///
+    /// Large zeroed allocations are often backed by lazily mapped pages that are only
+    /// committed on first access. This writes a zero byte every 4096 bytes, the page size
+    /// of most targets, to commit them up front. On targets with larger pages some writes
+    /// are redundant; with smaller pages some pages are left to fault on first use.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [`alloc_zeroed_boxed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    ///
+    /// let table = <[u64; 65536]>::alloc_zeroed_boxed_committed().unwrap();
+    /// assert!(table.iter().all(|&v| v == 0));
+    /// ```
+    ///
+    /// [`alloc_zeroed_boxed`]: AllocZeroedBoxed::alloc_zeroed_boxed
+    fn alloc_zeroed_boxed_committed() -> Result<Box<Self>, AllocError> {
+        const PAGE_SIZE: usize = 4096;
+
+        let value = Self::alloc_zeroed_boxed()?;
+        let base = Box::into_raw(value).cast::<u8>();
+
+        for offset in (0..std::mem::size_of::<Self>()).step_by(PAGE_SIZE) {
+            // SAFETY: `offset` is within the allocation, and the byte is already zero, so
+            // writing zero keeps the value intact. The write is volatile so that it is not
+            // optimized away.
+            unsafe { std::ptr::write_volatile(base.add(offset), 0) };
+        }
+
+        // SAFETY: `base` came from `Box::into_raw` just above
+        Ok(unsafe { Box::from_raw(base.cast::<Self>()) })
+    }
+
+    /// Allocates and zero-initializes an instance of `Self` on the heap, returning the box
+    /// together with the alignment its address actually has: the largest power of two
+    /// dividing it.
+    ///
+    /// This is a debugging aid for checking allocator behavior. The result is always at least
+    /// `align_of::<Self>()`, and often larger, since allocators hand out blocks aligned to
+    /// their size classes.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [`alloc_zeroed_boxed`](AllocZeroedBoxed::alloc_zeroed_boxed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    ///
+    /// let (value, align) = u64::alloc_zeroed_boxed_with_align().unwrap();
+    /// assert_eq!(*value, 0);
+    /// assert!(align >= align_of::<u64>());
+    /// ```
+    fn alloc_zeroed_boxed_with_align() -> Result<(Box<Self>, usize), AllocError> {
+        let value = Self::alloc_zeroed_boxed()?;
+        // Boxes are never null, so the address has a lowest set bit
+        let align = 1 << (&*value as *const Self).addr().trailing_zeros();
+
+        Ok((value, align))
+    }
+
+    /// Allocates and zero-initializes an instance of `Self` on the heap, returning the raw
+    /// pointer and the layout needed to free it instead of a `Box`.
+    ///
+    /// The caller owns the memory, e.g. to hand it over FFI, and must eventually release it
+    /// with [`std::alloc::dealloc`] using the returned layout, or rebuild a `Box` with
+    /// [`Box::from_raw`]. For zero-sized types the pointer is dangling and the layout has
+    /// size 0; such an allocation must not be passed to `dealloc`.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [`alloc_zeroed_boxed`](AllocZeroedBoxed::alloc_zeroed_boxed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    ///
+    /// let (ptr, layout) = <[u64; 32]>::alloc_zeroed_raw_parts().unwrap();
+    /// assert_eq!(unsafe { ptr.as_ref() }, &[0; 32]);
+    ///
+    /// // SAFETY: `ptr` was allocated with `layout` by the global allocator
+    /// unsafe { std::alloc::dealloc(ptr.as_ptr().cast(), layout) };
+    /// ```
+    fn alloc_zeroed_raw_parts() -> Result<(std::ptr::NonNull<Self>, std::alloc::Layout), AllocError>
+    {
+        use AllocErrorKind::*;
+        use std::alloc::{Layout, alloc_zeroed};
+        use std::ptr::NonNull;
+
+        let layout = Layout::new::<Self>();
+        crate::core::check_layout(layout.size(), layout.align())?;
+
+        if layout.size() == 0 {
+            return Ok((NonNull::dangling(), layout));
+        }
+
+        // SAFETY: The layout has a non-zero size
+        let ptr = unsafe { alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr.cast::<Self>()).ok_or_else(|| {
+            alloc_err!(OutOfMemory {
+                required: layout.size(),
+                alignment: layout.align(),
+            })
+            .with_type_name(std::any::type_name::<Self>())
+            .build()
+        })?;
+
+        Ok((ptr, layout))
+    }
+
+    /// Allocates a zero-initialized `Self` on the heap and converts the box into any pointer
+    /// type `P` that can be built from it.
+    ///
+    /// This covers `Rc`, `Arc` and user-defined smart pointers alike, as long as they
+    /// implement `From<Box<Self>>`. Note that `Rc` and `Arc` copy the value into a new
+    /// allocation that also holds their reference counts.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [`alloc_zeroed_boxed`](AllocZeroedBoxed::alloc_zeroed_boxed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    /// use std::sync::Arc;
+    ///
+    /// let shared: Arc<[u32; 16]> = <[u32; 16]>::alloc_zeroed_boxed_as().unwrap();
+    /// assert_eq!(*shared, [0; 16]);
+    /// ```
+    fn alloc_zeroed_boxed_as<P: From<Box<Self>>>() -> Result<P, AllocError> {
+        Self::alloc_zeroed_boxed().map(P::from)
+    }
+
+    /// Allocates a boxed slice of `count` zero-initialized `Self` values on the heap.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError` in the following cases:
+    /// * `AllocError::OutOfMemory` - The system allocator cannot fulfill the allocation request
+    /// * `AllocError::InvalidLayout` - The total size of the slice exceeds `isize::MAX` bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    ///
+    /// let values = u32::alloc_zeroed_boxed_slice(100).unwrap();
+    /// assert_eq!(values.len(), 100);
+    /// assert!(values.iter().all(|&v| v == 0));
+    /// ```
+    ///
+    /// # Zero-Sized Types
+    ///
+    /// For zero-sized types and for `count == 0`, no memory is allocated and the slice is
+    /// backed by a dangling pointer. Unlike the buffer API, the length is never capped: the
+    /// slice always holds exactly `count` elements, zero-sized types included.
+    fn alloc_zeroed_boxed_slice(count: usize) -> Result<Box<[Self]>, AllocError> {
+        use AllocErrorKind::*;
+        use std::alloc::{Layout, alloc_zeroed};
+
+        let type_name = std::any::type_name::<Self>();
+        let align = std::mem::align_of::<Self>();
+
+        let layout = Layout::array::<Self>(count).map_err(|_| {
+            alloc_err!(InvalidLayout {
+                size: std::mem::size_of::<Self>().saturating_mul(count),
+                alignment: align,
+            })
+            .with_type_name(type_name)
+            .build()
+        })?;
+
+        if layout.size() == 0 {
+            let dangling_ptr = std::ptr::NonNull::<Self>::dangling().as_ptr();
+            // SAFETY: A slice occupying zero bytes needs no allocation, only an aligned pointer
+            return Ok(unsafe {
+                Box::from_raw(std::ptr::slice_from_raw_parts_mut(dangling_ptr, count))
+            });
+        }
+
+        // SAFETY: The layout has a non-zero size, the null case is checked, and zeroed memory
+        // is a valid `[Self]` (guaranteed by AllocZeroed trait bound)
+        unsafe {
+            let ptr = alloc_zeroed(layout);
+            if ptr.is_null() {
+                return Err(alloc_err!(OutOfMemory {
+                    required: layout.size(),
+                    alignment: layout.align(),
+                })
+                .with_type_name(type_name)
+                .build());
+            }
+
+            Ok(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+                ptr as *mut Self,
+                count,
+            )))
+        }
+    }
+
+    /// Allocates a ragged 2D array: one boxed, zero-initialized row per entry in `lengths`.
+    ///
+    /// Empty rows do not allocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while allocating a row, see
+    /// [`alloc_zeroed_boxed_slice`]. Rows allocated before the failure are freed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    ///
+    /// let rows = u16::alloc_zeroed_boxed_rows(&[3, 0, 5]).unwrap();
+    /// assert_eq!(rows.len(), 3);
+    /// assert_eq!(rows[2].len(), 5);
+    /// ```
+    ///
+    /// [`alloc_zeroed_boxed_slice`]: AllocZeroedBoxed::alloc_zeroed_boxed_slice
+    fn alloc_zeroed_boxed_rows(lengths: &[usize]) -> Result<Box<[Box<[Self]>]>, AllocError> {
+        lengths
+            .iter()
+            .map(|&len| Self::alloc_zeroed_boxed_slice(len))
+            .collect()
+    }
+
+    /// Allocates a zero-initialized `Self` on the heap and publishes it into `target` if
+    /// `target` is still null, returning the pointer stored there afterwards.
+    ///
+    /// This is the allocate-then-`compare_exchange` step of lock-free lazy initialization.
+    /// If `target` is already set no allocation happens; if another thread publishes first,
+    /// the allocation made here is freed and the other thread's pointer is returned. Either
+    /// way every caller sees the same pointer, which was created with [`Box::into_raw`] and
+    /// stays owned by `target`: whoever clears `target` is responsible for freeing it with
+    /// [`Box::from_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Fails in the same cases as [`alloc_zeroed_boxed`](AllocZeroedBoxed::alloc_zeroed_boxed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    /// use std::sync::atomic::AtomicPtr;
+    ///
+    /// let slot = AtomicPtr::new(std::ptr::null_mut());
+    /// let first = <[u32; 8]>::alloc_zeroed_boxed_into_atomic(&slot).unwrap();
+    /// let second = <[u32; 8]>::alloc_zeroed_boxed_into_atomic(&slot).unwrap();
+    /// assert_eq!(first, second);
+    ///
+    /// // SAFETY: `slot` holds the only pointer to a `Box` created by `Box::into_raw`
+    /// drop(unsafe { Box::from_raw(slot.into_inner()) });
+    /// ```
+    fn alloc_zeroed_boxed_into_atomic(
+        target: &std::sync::atomic::AtomicPtr<Self>,
+    ) -> Result<*mut Self, AllocError> {
+        use std::sync::atomic::Ordering;
+
+        let current = target.load(Ordering::Acquire);
+        if !current.is_null() {
+            return Ok(current);
+        }
+
+        let candidate = Box::into_raw(Self::alloc_zeroed_boxed()?);
+
+        match target.compare_exchange(
+            std::ptr::null_mut(),
+            candidate,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => Ok(candidate),
+            Err(winner) => {
+                // SAFETY: `candidate` came from `Box::into_raw` and was never published
+                drop(unsafe { Box::from_raw(candidate) });
+                Ok(winner)
+            }
+        }
+    }
+
+    /// Allocates a `rows` x `cols` matrix of zero-initialized `Self` values on the heap, as
+    /// `rows` boxed slices of `cols` elements each.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllocError::InvalidLayout` if the matrix as a whole would exceed `isize::MAX`
+    /// bytes, before anything is allocated, and otherwise fails in the same cases as
+    /// [`alloc_zeroed_boxed_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::AllocZeroedBoxed;
+    ///
+    /// let matrix = f64::alloc_zeroed_boxed_matrix(3, 4).unwrap();
+    /// assert_eq!(matrix.len(), 3);
+    /// assert!(matrix.iter().all(|row| row.len() == 4));
+    /// ```
+    ///
+    /// [`alloc_zeroed_boxed_slice`]: AllocZeroedBoxed::alloc_zeroed_boxed_slice
+    fn alloc_zeroed_boxed_matrix(
+        rows: usize,
+        cols: usize,
+    ) -> Result<Box<[Box<[Self]>]>, AllocError> {
+        use AllocErrorKind::*;
+
+        let size = std::mem::size_of::<Self>();
+        let align = std::mem::align_of::<Self>();
+
+        let total_bytes = rows
+            .checked_mul(cols)
+            .and_then(|count| count.checked_mul(size));
+        match total_bytes {
+            Some(bytes) => crate::core::check_layout(bytes, align)?,
+            None => {
+                return Err(alloc_err!(InvalidLayout {
+                    size: usize::MAX,
+                    alignment: align,
+                })
+                .with_type_name(std::any::type_name::<Self>())
+                .build());
+            }
+        }
+
+        (0..rows)
+            .map(|_| Self::alloc_zeroed_boxed_slice(cols))
+            .collect()
+    }
+
+    /// Asserts that a heap allocation of `Self` has every byte, padding included, set to zero.
+    ///
+    /// This is a test utility: downstream crates can call it for their own types to check
+    /// that allocation leaves no byte uninitialized or non-zero. Run under Miri it also
+    /// reports bytes that were never initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocation fails or any byte is non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloc_zeroed::{AllocZeroed, AllocZeroedBoxed};
+    ///
+    /// #[derive(AllocZeroed)]
+    /// #[repr(C)]
+    /// struct Header {
+    ///     tag: u8,
+    ///     len: u32,
+    /// }
+    ///
+    /// Header::verify_zeroed_all_bytes();
+    /// ```
+    fn verify_zeroed_all_bytes() {
+        let value = Self::alloc_zeroed_boxed().expect("allocation failed");
+
+        // SAFETY: The allocation holds `size_of::<Self>()` bytes written by `alloc_zeroed`,
+        // including the padding, so they can all be read as `u8`
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                &*value as *const Self as *const u8,
+                std::mem::size_of::<Self>(),
+            )
+        };
+
+        if let Some(offset) = bytes.iter().position(|&byte| byte != 0) {
+            panic!(
+                "byte {} of {} is not zero",
+                offset,
+                std::any::type_name::<Self>()
+            );
+        }
+    }
 }
 
 impl<T: AllocZeroed> AllocZeroedBoxed for T {}
@@ -0,0 +1,137 @@
+use super::std;
+use std::alloc::{Layout, alloc_zeroed, dealloc};
+use std::cell::RefCell;
+use std::ptr::NonNull;
+
+use crate::{AllocError, AllocErrorKind, BufferAllocator, alloc_err};
+
+/// Size of the scratch buffer when [`configure_scratch`] was not called.
+const DEFAULT_SCRATCH_BYTES: usize = 4096;
+
+/// Base alignment of the scratch buffer when [`configure_scratch`] was not called.
+const DEFAULT_SCRATCH_ALIGN: usize = 16;
+
+std::thread_local! {
+    static SCRATCH: RefCell<Option<Scratch>> = const { RefCell::new(None) };
+}
+
+/// The heap block backing a thread's scratch buffer.
+struct Scratch {
+    ptr: NonNull<u8>,
+    len: usize,
+    layout: Layout,
+}
+
+impl Scratch {
+    fn new(bytes: usize, align: usize) -> Result<Self, AllocError> {
+        // Allocate at least one byte, the global allocator does not accept empty layouts
+        let layout = Layout::from_size_align(bytes.max(1), align).map_err(|_| {
+            alloc_err!(AllocErrorKind::InvalidLayout {
+                size: bytes,
+                alignment: align,
+            })
+            .with_context("thread-local scratch buffer")
+            .build()
+        })?;
+
+        // SAFETY: `layout` has a non-zero size
+        let ptr = NonNull::new(unsafe { alloc_zeroed(layout) }).ok_or_else(|| {
+            AllocError::out_of_memory(layout.size(), layout.align())
+                .with_context("thread-local scratch buffer")
+                .build()
+        })?;
+
+        Ok(Self {
+            ptr,
+            len: bytes,
+            layout,
+        })
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated with `layout` in `Scratch::new`
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+/// Sets the size and base alignment of the current thread's scratch buffer.
+///
+/// Must be called before the first [`with_scratch`] on the thread; without it the scratch
+/// buffer is 4096 bytes aligned to 16. The base alignment is the alignment of the first
+/// allocation made in each [`with_scratch`] call.
+///
+/// # Errors
+///
+/// Returns `AllocError::InvalidLayout` if `align` is not a power of two or the size is too
+/// large, and `AllocError::OutOfMemory` if the buffer cannot be allocated.
+///
+/// # Panics
+///
+/// Panics if the scratch buffer of the current thread was already configured or used.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::{configure_scratch, with_scratch};
+///
+/// configure_scratch(1024, 64).unwrap();
+///
+/// let addr = with_scratch(|alloc| alloc.alloc::<u8>().unwrap() as *mut u8 as usize);
+/// assert_eq!(addr % 64, 0);
+/// ```
+pub fn configure_scratch(bytes: usize, align: usize) -> Result<(), AllocError> {
+    SCRATCH.with(|scratch| {
+        let mut scratch = scratch.borrow_mut();
+        assert!(
+            scratch.is_none(),
+            "the scratch buffer of this thread is already initialized"
+        );
+
+        *scratch = Some(Scratch::new(bytes, align)?);
+
+        Ok(())
+    })
+}
+
+/// Runs `f` with an allocator over the current thread's scratch buffer.
+///
+/// Every call starts again at the beginning of the buffer, and values allocated through the
+/// allocator cannot outlive `f`. The buffer is created on first use with the settings from
+/// [`configure_scratch`], or the defaults if it was not called.
+///
+/// # Panics
+///
+/// Panics if the default scratch buffer cannot be allocated, or if called from within `f`.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::with_scratch;
+///
+/// let sum: u64 = with_scratch(|alloc| {
+///     let values = alloc.alloc_slice::<u64>(8).unwrap();
+///     values[3] = 5;
+///     values.iter().sum()
+/// });
+/// assert_eq!(sum, 5);
+/// ```
+pub fn with_scratch<R>(f: impl FnOnce(&mut BufferAllocator<'_>) -> R) -> R {
+    SCRATCH.with(|scratch| {
+        let mut scratch = scratch.borrow_mut();
+        let scratch = match &mut *scratch {
+            Some(scratch) => scratch,
+            slot @ None => slot.insert(
+                Scratch::new(DEFAULT_SCRATCH_BYTES, DEFAULT_SCRATCH_ALIGN)
+                    .expect("failed to allocate the scratch buffer"),
+            ),
+        };
+
+        // SAFETY: The block is valid for `len` bytes and the `RefCell` borrow makes this the
+        // only reference to it until `f` returns
+        let mem = unsafe { std::slice::from_raw_parts_mut(scratch.ptr.as_ptr(), scratch.len) };
+
+        f(&mut BufferAllocator::new(mem))
+    })
+}
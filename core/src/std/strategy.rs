@@ -0,0 +1,61 @@
+use super::std;
+use std::alloc::{Layout, dealloc};
+use std::boxed::Box;
+use std::cell::RefCell;
+use std::ptr::NonNull;
+use std::vec::Vec;
+
+use crate::{AllocError, AllocStrategy, AllocZeroed, AllocZeroedBoxed};
+
+/// An [`AllocStrategy`] backed by the global allocator.
+///
+/// Allocations are owned by the `HeapStrategy` and freed when it is dropped, so the
+/// strategy is used through a shared reference (`&HeapStrategy`). As with buffer
+/// allocations, destructors of the allocated values are not run.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::{AllocStrategy, HeapStrategy};
+///
+/// let heap = HeapStrategy::new();
+/// let mut strategy = &heap;
+/// let value: &mut [u64; 128] = strategy.allocate_zeroed().unwrap();
+/// assert_eq!(*value, [0; 128]);
+/// ```
+#[derive(Debug, Default)]
+pub struct HeapStrategy {
+    allocations: RefCell<Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl HeapStrategy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<'a> AllocStrategy<'a> for &'a HeapStrategy {
+    fn allocate_zeroed<T: AllocZeroed + 'a>(&mut self) -> Result<&'a mut T, AllocError> {
+        let ptr = Box::into_raw(T::alloc_zeroed_boxed()?);
+
+        let layout = Layout::new::<T>();
+        if layout.size() != 0 {
+            // SAFETY: Box::into_raw never returns null
+            let ptr = unsafe { NonNull::new_unchecked(ptr as *mut u8) };
+            self.allocations.borrow_mut().push((ptr, layout));
+        }
+
+        // SAFETY: The allocation stays alive until the strategy is dropped, which the
+        // borrow `'a` prevents while the reference is in use
+        Ok(unsafe { &mut *ptr })
+    }
+}
+
+impl Drop for HeapStrategy {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().drain(..) {
+            // SAFETY: Each entry was allocated by the global allocator with this layout
+            unsafe { dealloc(ptr.as_ptr(), layout) };
+        }
+    }
+}
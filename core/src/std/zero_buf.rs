@@ -0,0 +1,100 @@
+use super::std;
+use std::boxed::Box;
+use std::cell::Cell;
+use std::ptr::NonNull;
+
+use crate::{AllocError, AllocZeroed, AllocZeroedBoxed, BufferAllocator};
+
+/// A heap buffer, zeroed once on creation, that hands out typed zero-initialized values.
+///
+/// This keeps the backing memory and the allocation cursor together, so values can be
+/// allocated through a shared reference and stay valid as long as the `ZeroBuf` is borrowed.
+/// Allocations are carved sequentially by a [`BufferAllocator`]; use [`reset`] to reclaim
+/// the space once no values are in use.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::ZeroBuf;
+///
+/// let buf = ZeroBuf::with_capacity(1024).unwrap();
+/// let header = buf.alloc::<u32>().unwrap();
+/// let body = buf.alloc_slice::<u64>(16).unwrap();
+///
+/// *header = body.len() as u32;
+/// assert_eq!(body, [0; 16]);
+/// ```
+///
+/// [`reset`]: ZeroBuf::reset
+#[derive(Debug)]
+pub struct ZeroBuf {
+    mem: NonNull<[u8]>,
+    position: Cell<usize>,
+}
+
+impl ZeroBuf {
+    /// Allocates a zeroed buffer of `bytes` bytes on the heap.
+    pub fn with_capacity(bytes: usize) -> Result<Self, AllocError> {
+        let mem = NonNull::from(Box::leak(u8::alloc_zeroed_boxed_slice(bytes)?));
+
+        Ok(Self {
+            mem,
+            position: Cell::new(0),
+        })
+    }
+
+    /// Returns the total size of the buffer in bytes.
+    pub fn capacity(&self) -> usize {
+        self.mem.len()
+    }
+
+    /// Returns the number of bytes not yet handed out.
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.position.get()
+    }
+
+    /// Allocates a zero-initialized `T` from the buffer.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: AllocZeroed>(&self) -> Result<&mut T, AllocError> {
+        let slice = self.alloc_slice::<T>(1)?;
+
+        Ok(&mut slice[0])
+    }
+
+    /// Allocates a slice of `count` zero-initialized `T` values from the buffer.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice<T: AllocZeroed>(&self, count: usize) -> Result<&mut [T], AllocError> {
+        let position = self.position.get();
+
+        // SAFETY: The bytes from `position` onward have never been handed out, so this is
+        // the only reference to them, and they live as long as `self`
+        let tail = unsafe {
+            let start = self.mem.as_ptr().cast::<u8>().add(position);
+            std::slice::from_raw_parts_mut(start, self.capacity() - position)
+        };
+
+        let mut allocator = BufferAllocator::new(tail);
+        let slice = allocator.alloc_slice::<T>(count)?;
+        self.position.set(position + allocator.position());
+
+        Ok(slice)
+    }
+
+    /// Re-zeroes the used part of the buffer and makes all of it available again.
+    ///
+    /// Taking `&mut self` guarantees that no previously allocated values are still in use.
+    pub fn reset(&mut self) {
+        let position = self.position.replace(0);
+
+        // SAFETY: `&mut self` guarantees no allocation from the buffer is still borrowed
+        let mem = unsafe { self.mem.as_mut() };
+        mem[..position].fill(0);
+    }
+}
+
+impl Drop for ZeroBuf {
+    fn drop(&mut self) {
+        // SAFETY: `mem` was leaked from a `Box<[u8]>` in `with_capacity`
+        drop(unsafe { Box::from_raw(self.mem.as_ptr()) });
+    }
+}
@@ -0,0 +1,55 @@
+use super::std;
+use std::boxed::Box;
+use std::ops::{Deref, DerefMut};
+
+use crate::{AllocError, AllocZeroed, AllocZeroedBoxed};
+
+/// A `Box<T>` that was allocated zero-initialized.
+///
+/// This is a thin wrapper that dereferences to `T`. With the `stable_deref_trait` feature it
+/// implements `StableDeref`, so it can be used with crates that build self-referential
+/// structures over boxes.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::ZeroedBox;
+///
+/// let mut value = ZeroedBox::<[u64; 16]>::new().unwrap();
+/// value[3] = 7;
+/// assert_eq!(value[3], 7);
+/// assert_eq!(value[4], 0);
+/// ```
+#[derive(Debug)]
+pub struct ZeroedBox<T: AllocZeroed>(Box<T>);
+
+impl<T: AllocZeroed> ZeroedBox<T> {
+    /// Allocates a zero-initialized `T` on the heap, see [`AllocZeroedBoxed::alloc_zeroed_boxed`].
+    pub fn new() -> Result<Self, AllocError> {
+        T::alloc_zeroed_boxed().map(Self)
+    }
+
+    /// Returns the underlying box.
+    pub fn into_inner(self) -> Box<T> {
+        self.0
+    }
+}
+
+impl<T: AllocZeroed> Deref for ZeroedBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AllocZeroed> DerefMut for ZeroedBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+// SAFETY: The target lives in a heap allocation owned by the box, so its address does not
+// change when the `ZeroedBox` is moved.
+#[cfg(feature = "stable_deref_trait")]
+unsafe impl<T: AllocZeroed> stable_deref_trait::StableDeref for ZeroedBox<T> {}
@@ -0,0 +1,81 @@
+use super::std;
+use std::boxed::Box;
+use std::vec::Vec;
+
+use crate::{AllocError, AllocZeroed, AllocZeroedBoxed};
+
+/// A pool that recycles boxed allocations of a fixed-size type.
+///
+/// Released boxes are kept on a free list and handed out again, zeroed, by [`acquire`],
+/// which only falls back to the global allocator when the list is empty. This reduces
+/// allocator pressure when objects are created and dropped at a high rate.
+///
+/// # Examples
+///
+/// ```
+/// use alloc_zeroed::ZeroedPool;
+///
+/// let mut pool = ZeroedPool::<[u64; 64]>::new();
+///
+/// let mut buffer = pool.acquire().unwrap();
+/// buffer[0] = 42;
+/// pool.release(buffer);
+///
+/// let buffer = pool.acquire().unwrap();
+/// assert_eq!(*buffer, [0; 64]);
+/// ```
+///
+/// [`acquire`]: ZeroedPool::acquire
+#[derive(Debug)]
+pub struct ZeroedPool<T> {
+    free: Vec<Box<T>>,
+}
+
+impl<T: AllocZeroed> ZeroedPool<T> {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Self { free: Vec::new() }
+    }
+
+    /// Returns the number of released boxes waiting to be reused.
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns a zero-initialized box, reusing a released one if possible.
+    ///
+    /// The previous value of a reused box is dropped before it is zeroed.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`alloc_zeroed_boxed`] when a new box has to be allocated.
+    ///
+    /// [`alloc_zeroed_boxed`]: AllocZeroedBoxed::alloc_zeroed_boxed
+    pub fn acquire(&mut self) -> Result<Box<T>, AllocError> {
+        let Some(recycled) = self.free.pop() else {
+            return T::alloc_zeroed_boxed();
+        };
+
+        let ptr = Box::into_raw(recycled);
+
+        // SAFETY: `ptr` comes from a live box. The old value is dropped exactly once before
+        // its bytes are zeroed, which is a valid `T` per the trait contract. If the drop
+        // panics, the allocation is leaked rather than reused.
+        unsafe {
+            std::ptr::drop_in_place(ptr);
+            std::ptr::write_bytes(ptr, 0, 1);
+            Ok(Box::from_raw(ptr))
+        }
+    }
+
+    /// Returns a box to the pool for reuse.
+    pub fn release(&mut self, value: Box<T>) {
+        self.free.push(value);
+    }
+}
+
+impl<T: AllocZeroed> Default for ZeroedPool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,28 @@
+use alloc_zeroed::{AllocZeroed, AllocZeroedBoxed};
+use generic_array::GenericArray;
+use generic_array::typenum::{U4, U16};
+
+#[test]
+fn generic_array_is_zeroed() {
+    let mut buffer = [0xFFu8; 128];
+    let array = GenericArray::<u32, U16>::alloc_zeroed(&mut buffer).unwrap();
+    assert_eq!(array.len(), 16);
+    assert!(array.iter().all(|&v| v == 0));
+
+    let boxed = GenericArray::<u64, U16>::alloc_zeroed_boxed().unwrap();
+    assert!(boxed.iter().all(|&v| v == 0));
+}
+
+#[test]
+fn generic_array_fields_derive() {
+    #[derive(AllocZeroed)]
+    struct Block {
+        state: GenericArray<u32, U4>,
+        counter: u64,
+    }
+
+    let mut buffer = [0xFFu8; 64];
+    let block = Block::alloc_zeroed(&mut buffer).unwrap();
+    assert!(block.state.iter().all(|&v| v == 0));
+    assert_eq!(block.counter, 0);
+}
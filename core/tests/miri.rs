@@ -48,3 +48,221 @@ fn miri_test_buffer_allocation() {
         assert_eq!(*float_ref, std::f64::consts::PI);
     }
 }
+
+#[test]
+fn miri_test_nonnull_intrusive_list() {
+    use std::ptr::NonNull;
+
+    struct Node {
+        next: Option<NonNull<Node>>,
+        value: u32,
+    }
+
+    // SAFETY: A null `next` is `None` and a zero `value` is valid
+    unsafe impl AllocZeroed for Node {}
+
+    let mut buffer = [0xFFu8; 128];
+    let (first_mem, second_mem) = buffer.split_at_mut(64);
+
+    let first = Node::alloc_zeroed_nonnull(first_mem).unwrap();
+    let second = Node::alloc_zeroed_nonnull(second_mem).unwrap();
+
+    // SAFETY: Both halves of `buffer` outlive the nodes and are only accessed through them
+    unsafe {
+        assert!((*first.as_ptr()).next.is_none());
+        assert_eq!((*second.as_ptr()).value, 0);
+
+        (*first.as_ptr()).value = 1;
+        (*first.as_ptr()).next = Some(second);
+        (*second.as_ptr()).value = 2;
+        (*second.as_ptr()).next = Some(first);
+
+        let next = (*first.as_ptr()).next.unwrap();
+        assert_eq!((*next.as_ptr()).value, 2);
+        let back = (*next.as_ptr()).next.unwrap();
+        assert_eq!(back, first);
+        assert_eq!((*back.as_ptr()).value, 1);
+    }
+}
+
+#[test]
+fn miri_test_maybe_uninit_bulk_init() {
+    #[derive(Debug, PartialEq)]
+    struct Sample {
+        channel: u16,
+        value: f32,
+    }
+
+    unsafe impl AllocZeroed for Sample {}
+
+    let mut buffer = [0xA5u8; 1024];
+
+    let slots = Sample::alloc_zeroed_maybe_uninit_slice(&mut buffer, 100).unwrap();
+    assert_eq!(slots.len(), 100);
+
+    let samples = Sample::init_all(slots);
+    assert_eq!(samples.len(), 100);
+    for sample in samples.iter() {
+        assert_eq!(
+            *sample,
+            Sample {
+                channel: 0,
+                value: 0.0
+            }
+        );
+    }
+
+    samples[99].channel = 3;
+    assert_eq!(samples[99].channel, 3);
+}
+
+#[test]
+fn miri_test_reclaim_buffer() {
+    #[repr(C, align(8))]
+    struct AlignedBuffer([u8; 64]);
+
+    let mut buffer = AlignedBuffer([0xFF; 64]);
+
+    let mut value = <[u64; 4]>::alloc_zeroed_reclaimable(&mut buffer.0[1..]).unwrap();
+    value[0] = u64::MAX;
+    assert_eq!(value[1], 0);
+
+    // The reclaimed slice covers the padding before the object as well
+    let mem = value.reclaim();
+    assert_eq!(mem.len(), 63);
+    assert_eq!(mem[0], 0xFF);
+    assert_eq!(mem[7..15], [0xFF; 8]);
+
+    // Reuse the whole region for a different type
+    let reused = <[u32; 15]>::alloc_zeroed(mem).unwrap();
+    for &item in reused.iter() {
+        assert_eq!(item, 0);
+    }
+}
+
+#[test]
+fn miri_test_aligned_buffer_allocation() {
+    // Miri may make `align_offset` fail at random; allocation must not depend on it
+    #[repr(C, align(16))]
+    struct AlignedBuffer([u8; 256]);
+
+    let mut buffer = AlignedBuffer([0xFF; 256]);
+
+    for _ in 0..32 {
+        let (first, rest) = u64::alloc_zeroed_with_remainder(&mut buffer.0).unwrap();
+        assert_eq!(*first, 0);
+        assert_eq!(first as *mut u64 as usize % 8, 0);
+
+        let values = u32::alloc_zeroed_slice(rest).unwrap();
+        assert_eq!(values.len(), 62);
+        assert!(values.iter().all(|&v| v == 0));
+    }
+}
+
+#[test]
+fn miri_test_raw_parts_round_trip() {
+    #[derive(AllocZeroed)]
+    struct Record {
+        id: u64,
+        values: [u16; 12],
+    }
+
+    let (ptr, layout) = Record::alloc_zeroed_raw_parts().unwrap();
+    assert_eq!(layout, std::alloc::Layout::new::<Record>());
+
+    // SAFETY: `ptr` points to a live, zeroed `Record` owned by this test
+    let record = unsafe { &mut *ptr.as_ptr() };
+    assert_eq!(record.id, 0);
+    assert_eq!(record.values, [0; 12]);
+    record.id = 7;
+
+    // SAFETY: `ptr` was allocated with `layout` and is no longer referenced
+    unsafe { std::alloc::dealloc(ptr.as_ptr().cast(), layout) };
+
+    // Zero-sized types get a dangling pointer and an empty layout
+    let (_, layout) = <[u64; 0]>::alloc_zeroed_raw_parts().unwrap();
+    assert_eq!(layout.size(), 0);
+}
+
+#[test]
+fn miri_test_boxed_into_atomic_single_winner() {
+    use std::sync::atomic::AtomicPtr;
+
+    let slot = AtomicPtr::<[u64; 4]>::new(std::ptr::null_mut());
+
+    let published: Vec<usize> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                scope.spawn(|| <[u64; 4]>::alloc_zeroed_boxed_into_atomic(&slot).unwrap() as usize)
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    let winner = slot.into_inner();
+    assert!(published.iter().all(|&ptr| ptr == winner as usize));
+
+    // SAFETY: The slot owns the only surviving allocation; the losers freed theirs
+    let value = unsafe { Box::from_raw(winner) };
+    assert_eq!(*value, [0; 4]);
+}
+
+#[test]
+fn miri_test_dual_view_alternating_access() {
+    #[derive(AllocZeroed)]
+    #[repr(C)]
+    struct Packet {
+        kind: u16,
+        length: u16,
+        checksum: u32,
+    }
+
+    let mut buffer = [0xFFu8; 32];
+    let mut view = Packet::alloc_zeroed_dual(&mut buffer).unwrap();
+    let packet = view.as_ptr().as_ptr();
+
+    // SAFETY: The pointer is valid and no byte view is alive
+    unsafe {
+        (*packet).kind = 0x0102;
+        (*packet).length = 8;
+    }
+
+    // SAFETY: `Packet` has no padding and its fields were written one by one, so every byte
+    // is initialized; the typed pointer is not used while `bytes` is alive
+    let sum: u32 = unsafe { view.bytes() }.iter().map(|&b| u32::from(b)).sum();
+    assert_eq!(sum, 0x01 + 0x02 + 8);
+
+    // SAFETY: The byte view is no longer used
+    unsafe { (*packet).checksum = sum };
+    assert_eq!(unsafe { (*view.as_ptr().as_ptr()).checksum }, 11);
+}
+
+#[test]
+fn miri_test_tx_allocator_commit_keeps_allocations() {
+    #[repr(align(8))]
+    struct Aligned([u8; 64]);
+
+    let mut buffer = Aligned([0xFF; 64]);
+    let mut allocator = alloc_zeroed::BufferAllocator::new(&mut buffer.0);
+
+    let mut tx = allocator.transaction();
+    let first = tx.alloc::<u32>().unwrap();
+    let second = tx.alloc_slice::<u16>(2).unwrap();
+    tx.commit();
+
+    // Allocations stay usable after the commit re-derived the parent's buffer
+    *first = 0x0101_0101;
+    second[1] = 0x0202;
+
+    let third = allocator.alloc::<u64>().unwrap();
+    *third = u64::MAX;
+
+    {
+        let mut tx = allocator.transaction();
+        *tx.alloc::<u64>().unwrap() = 4;
+    }
+    assert_eq!(*allocator.alloc::<u64>().unwrap(), 0);
+
+    assert_eq!(buffer.0[..8], [1, 1, 1, 1, 0, 0, 2, 2]);
+    assert_eq!(buffer.0[8..16], [0xFF; 8]);
+}
@@ -2,4 +2,19 @@
 fn ui_tests() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/derive_errors.rs");
+    t.compile_fail("tests/ui/derive_reference_field.rs");
+    t.compile_fail("tests/ui/derive_nonnull_field.rs");
+    t.compile_fail("tests/ui/derive_control_flow_field.rs");
+    t.compile_fail("tests/ui/derive_atomic_ordering_field.rs");
+    t.compile_fail("tests/ui/derive_from_bytes_missing.rs");
+    t.compile_fail("tests/ui/derive_from_bytes_invalid.rs");
+    t.compile_fail("tests/ui/derive_is_zero_maybe_uninit.rs");
+    t.pass("tests/ui/derive_const_generics.rs");
+    t.pass("tests/ui/derive_lifetimes.rs");
+    t.pass("tests/ui/derive_cfg_fields.rs");
+    t.pass("tests/ui/derive_from_bytes.rs");
+    t.pass("tests/ui/derive_phantom_pinned.rs");
+    t.pass("tests/ui/const_buffer_size.rs");
+    t.pass("tests/ui/derive_qualified_paths.rs");
+    t.pass("tests/ui/derive_own_padding_const.rs");
 }
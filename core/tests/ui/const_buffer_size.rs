@@ -0,0 +1,39 @@
+use alloc_zeroed::{AllocZeroed, BufferAllocator, const_buffer_size};
+
+#[derive(AllocZeroed)]
+struct Header {
+    magic: u32,
+    len: u16,
+}
+
+#[derive(AllocZeroed)]
+#[repr(align(16))]
+struct Body {
+    data: [u8; 24],
+}
+
+#[derive(AllocZeroed)]
+struct Item {
+    id: u64,
+    flag: bool,
+}
+
+const RECORD_BYTES: usize = const_buffer_size!(Header, Body, [Item; 8]);
+
+fn carve(buffer: &mut [u8]) {
+    let mut allocator = BufferAllocator::new(buffer);
+    allocator.alloc::<Header>().unwrap();
+    allocator.alloc::<Body>().unwrap();
+    allocator.alloc::<[Item; 8]>().unwrap();
+}
+
+fn main() {
+    let mut buffer = [0xFFu8; RECORD_BYTES];
+
+    // The bound holds at every starting offset, not just for this particular address
+    for start in 0..16 {
+        let mut shifted = [0xFFu8; RECORD_BYTES + 16];
+        carve(&mut shifted[start..start + RECORD_BYTES]);
+    }
+    carve(&mut buffer);
+}
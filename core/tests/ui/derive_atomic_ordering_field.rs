@@ -0,0 +1,13 @@
+use std::sync::atomic;
+
+use alloc_zeroed::AllocZeroed;
+
+// This should fail to compile because atomic::Ordering has no guaranteed zero variant
+#[derive(AllocZeroed)]
+struct Config {
+    retries: u32,
+    order: atomic::Ordering,
+    fallback: std::sync::atomic::Ordering,
+}
+
+fn main() {}
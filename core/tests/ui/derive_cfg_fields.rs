@@ -0,0 +1,33 @@
+use alloc_zeroed::AllocZeroed;
+
+// Doc comments and `cfg`-disabled fields are not part of the derive input
+#[derive(AllocZeroed)]
+struct Record {
+    /// The record identifier.
+    id: u64,
+    /// Never compiled; `cfg(any())` is always false.
+    #[cfg(any())]
+    name: &'static str,
+    #[cfg(all())]
+    #[doc = "Present in the test build."]
+    flags: u32,
+}
+
+// A disabled field that mentions a type parameter adds no bound
+#[derive(AllocZeroed)]
+struct Holder<T> {
+    #[cfg(any())]
+    value: T,
+    len: usize,
+    _p: std::marker::PhantomData<T>,
+}
+
+fn main() {
+    let mut buf = [0u8; 64];
+    let (record, rest) = Record::alloc_zeroed_with_remainder(&mut buf).unwrap();
+    assert_eq!(record.id, 0);
+    assert_eq!(record.flags, 0);
+
+    let holder = Holder::<u64>::alloc_zeroed(rest).unwrap();
+    assert_eq!(holder.len, 0);
+}
@@ -0,0 +1,29 @@
+use alloc_zeroed::AllocZeroed;
+
+// Inline string where all-zero means empty
+#[derive(AllocZeroed)]
+struct FixedStr<const N: usize> {
+    len: u8,
+    bytes: [u8; N],
+}
+
+// Type and const parameters mixed, with an existing where clause
+#[derive(AllocZeroed)]
+struct Ring<T, const N: usize>
+where
+    T: Copy,
+{
+    head: usize,
+    items: [T; N],
+}
+
+fn main() {
+    let mut buf = [0u8; 256];
+    let (fixed, rest) = FixedStr::<32>::alloc_zeroed_with_remainder(&mut buf).unwrap();
+    assert_eq!(fixed.len, 0);
+    assert_eq!(fixed.bytes, [0; 32]);
+
+    let ring = Ring::<u16, 8>::alloc_zeroed(rest).unwrap();
+    assert_eq!(ring.head, 0);
+    assert_eq!(ring.items, [0; 8]);
+}
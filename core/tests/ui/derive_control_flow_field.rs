@@ -0,0 +1,12 @@
+use std::ops::ControlFlow;
+
+use alloc_zeroed::AllocZeroed;
+
+// This should fail to compile because no ControlFlow variant is guaranteed to be all zeros
+#[derive(AllocZeroed)]
+struct Step {
+    index: usize,
+    flow: ControlFlow<(), ()>,
+}
+
+fn main() {}
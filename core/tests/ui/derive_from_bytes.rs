@@ -0,0 +1,15 @@
+use alloc_zeroed::AllocZeroed;
+
+#[derive(AllocZeroed)]
+#[alloc_zeroed(from_bytes)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u32,
+}
+
+fn main() {
+    let header = Header::from([1, 0, 0, 0, 8, 0, 0, 0]);
+    assert_eq!(header.magic, u32::from_ne_bytes([1, 0, 0, 0]));
+    assert_eq!(header.len, u32::from_ne_bytes([8, 0, 0, 0]));
+}
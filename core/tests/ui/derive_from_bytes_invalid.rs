@@ -0,0 +1,21 @@
+use alloc_zeroed::AllocZeroed;
+
+// `bool` only accepts 0 and 1, so not every byte is a valid flag
+#[derive(AllocZeroed)]
+#[alloc_zeroed(from_bytes)]
+struct Flag {
+    on: bool,
+}
+
+// The padding after `kind` cannot hold arbitrary bytes
+#[derive(AllocZeroed)]
+#[alloc_zeroed(from_bytes)]
+#[repr(C)]
+struct Padded {
+    kind: u8,
+    len: u32,
+}
+
+fn main() {
+    let _flag = Flag::from([2u8]);
+}
@@ -0,0 +1,18 @@
+use alloc_zeroed::AllocZeroed;
+
+#[derive(AllocZeroed)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u32,
+}
+
+#[derive(AllocZeroed)]
+#[alloc_zeroed(from_bytes)]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn main() {
+    let _header = Header::from([0u8; 8]);
+}
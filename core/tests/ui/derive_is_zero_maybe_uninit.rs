@@ -0,0 +1,13 @@
+use std::mem::MaybeUninit;
+
+use alloc_zeroed::AllocZeroed;
+
+// Reading a `MaybeUninit` field could observe uninitialized bytes
+#[derive(AllocZeroed)]
+#[alloc_zeroed(is_zero)]
+struct Scratch {
+    len: u32,
+    raw: MaybeUninit<u64>,
+}
+
+fn main() {}
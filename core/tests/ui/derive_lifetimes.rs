@@ -0,0 +1,26 @@
+use std::marker::PhantomData;
+
+use alloc_zeroed::AllocZeroed;
+
+// Lifetime parameters only appear through PhantomData, which is zeroable
+#[derive(AllocZeroed)]
+struct Tag<'a> {
+    _p: PhantomData<&'a ()>,
+    n: u32,
+}
+
+// Lifetimes mixed with type parameters
+#[derive(AllocZeroed)]
+struct Tagged<'a, 'b: 'a, T> {
+    _p: PhantomData<(&'a (), &'b ())>,
+    value: T,
+}
+
+fn main() {
+    let mut buf = [0u8; 64];
+    let (tag, rest) = Tag::alloc_zeroed_with_remainder(&mut buf).unwrap();
+    assert_eq!(tag.n, 0);
+
+    let tagged = Tagged::<u64>::alloc_zeroed(rest).unwrap();
+    assert_eq!(tagged.value, 0);
+}
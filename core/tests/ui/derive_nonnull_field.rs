@@ -0,0 +1,19 @@
+use std::ptr::NonNull;
+
+use alloc_zeroed::AllocZeroed;
+
+// This should fail to compile because a NonNull can never be null
+#[derive(AllocZeroed)]
+struct Node {
+    value: u32,
+    next: NonNull<Node>,
+}
+
+// Wrapping it in Option is accepted
+#[derive(AllocZeroed)]
+struct OptionalNode {
+    value: u32,
+    next: Option<NonNull<OptionalNode>>,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use alloc_zeroed::AllocZeroed;
+
+// The derive only adds `PADDING_BYTES` on request, so a type may define its own
+#[derive(AllocZeroed)]
+struct Frame {
+    len: u16,
+    data: [u8; 30],
+}
+
+impl Frame {
+    const PADDING_BYTES: usize = 2;
+}
+
+fn main() {
+    let mut buf = [0xFFu8; 64];
+    let frame = Frame::alloc_zeroed(&mut buf).unwrap();
+    assert_eq!(frame.data.len() + Frame::PADDING_BYTES, 32);
+}
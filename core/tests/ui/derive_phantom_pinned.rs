@@ -0,0 +1,26 @@
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+use alloc_zeroed::AllocZeroed;
+
+// Self-referential types opt out of `Unpin` with a zero-sized PhantomPinned field
+#[derive(AllocZeroed)]
+struct Node {
+    value: u64,
+    this: Option<NonNull<Node>>,
+    _pin: PhantomPinned,
+}
+
+fn main() {
+    let mut buf = [0xFFu8; 64];
+    let node = Node::alloc_zeroed(&mut buf).unwrap();
+    assert_eq!(node.value, 0);
+    assert!(node.this.is_none());
+
+    // SAFETY: the node is never moved out of the buffer while pinned
+    let mut node = unsafe { Pin::new_unchecked(node) };
+    let this = NonNull::from(&*node);
+    unsafe { node.as_mut().get_unchecked_mut().this = Some(this) };
+    assert_eq!(node.this, Some(this));
+}
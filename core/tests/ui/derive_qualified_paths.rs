@@ -0,0 +1,39 @@
+// Neither the trait nor the field types are imported where the derive is used
+
+mod types {
+    #[derive(alloc_zeroed::AllocZeroed)]
+    pub struct Matrix {
+        pub cells: [[f32; 4]; 4],
+    }
+}
+
+mod scene {
+    pub mod nodes {
+        #[derive(alloc_zeroed::AllocZeroed)]
+        pub struct Transform {
+            pub local: crate::types::Matrix,
+            pub world: super::super::types::Matrix,
+            pub flags: ::core::primitive::u32,
+            pub parent: ::core::option::Option<::core::ptr::NonNull<Transform>>,
+        }
+
+        #[derive(alloc_zeroed::AllocZeroed)]
+        pub struct Slot<T> {
+            pub value: T,
+            pub generation: core::num::Saturating<u32>,
+        }
+    }
+}
+
+fn main() {
+    use alloc_zeroed::AllocZeroed;
+
+    let mut buf = [0xFFu8; 512];
+    let (transform, rest) = scene::nodes::Transform::alloc_zeroed_with_remainder(&mut buf).unwrap();
+    assert_eq!(transform.local.cells, [[0.0; 4]; 4]);
+    assert_eq!(transform.world.cells[3][3], 0.0);
+    assert!(transform.parent.is_none());
+
+    let slot = scene::nodes::Slot::<types::Matrix>::alloc_zeroed(rest).unwrap();
+    assert_eq!(slot.generation.0, 0);
+}
@@ -0,0 +1,9 @@
+use alloc_zeroed::AllocZeroed;
+
+// This should fail to compile because a reference can never be null
+#[derive(AllocZeroed)]
+struct View<'a> {
+    data: &'a mut [u8],
+}
+
+fn main() {}
@@ -1,13 +1,117 @@
 // macros/src/lib.rs
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{Data, DeriveInput, parse_macro_input};
+use proc_macro2::TokenTree;
+use quote::{ToTokens, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Ident, Type, parse_macro_input};
 
-#[proc_macro_derive(AllocZeroed)]
+/// Options set through `#[alloc_zeroed(...)]` attributes on the derived type.
+#[derive(Default)]
+struct Options {
+    is_zero: bool,
+    /// Emits `From<[u8; size_of::<Self>()]>`. This is only sound when *every* bit pattern is
+    /// a valid value of the type, not just all zeros, which is checked at compile time.
+    from_bytes: bool,
+    /// Emits an inherent `PADDING_BYTES` constant.
+    padding_bytes: bool,
+}
+
+impl Options {
+    fn from_attributes(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Options::default();
+
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("alloc_zeroed"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("is_zero") {
+                    options.is_zero = true;
+                    Ok(())
+                } else if meta.path.is_ident("from_bytes") {
+                    options.from_bytes = true;
+                    Ok(())
+                } else if meta.path.is_ident("padding_bytes") {
+                    options.padding_bytes = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown alloc_zeroed option"))
+                }
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Returns `true` if `ty` refers to any of the given type parameters.
+fn mentions_type_param(ty: &Type, params: &[&Ident]) -> bool {
+    fn scan(tokens: proc_macro2::TokenStream, params: &[&Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => params.iter().any(|param| **param == ident),
+            TokenTree::Group(group) => scan(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    scan(ty.to_token_stream(), params)
+}
+
+/// Types known to have no valid all-zero value, matched by the trailing segments of their
+/// path, with the error reported for a field of that type.
+const REJECTED_TYPES: &[(&[&str], &str)] = &[
+    (
+        &["NonNull"],
+        "`NonNull` can never be null, so it cannot be zero-initialized; \
+         use `Option<NonNull<T>>`, which zero-initializes to `None`",
+    ),
+    (
+        &["ControlFlow"],
+        "`ControlFlow` is an enum whose discriminant is not guaranteed to be zero for any \
+         variant, so it cannot be zero-initialized; store the payload and a `bool` or integer \
+         flag instead",
+    ),
+    (
+        &["atomic", "Ordering"],
+        "`atomic::Ordering` is a memory ordering enum without a guaranteed zero variant, so it \
+         cannot be zero-initialized (not to be confused with `cmp::Ordering`); store it as a \
+         `u8` and convert when used",
+    ),
+];
+
+/// Returns a targeted error for field types that are known to have no valid all-zero value.
+fn check_field_type(ty: &Type) -> Option<syn::Error> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let segments = &type_path.path.segments;
+
+    REJECTED_TYPES
+        .iter()
+        .find(|(suffix, _)| {
+            segments.len() >= suffix.len()
+                && segments
+                    .iter()
+                    .skip(segments.len() - suffix.len())
+                    .zip(suffix.iter())
+                    .all(|(segment, name)| segment.ident == name)
+        })
+        .map(|(_, message)| syn::Error::new_spanned(ty, message))
+}
+
+#[proc_macro_derive(AllocZeroed, attributes(alloc_zeroed))]
 pub fn derive_alloc_zeroed(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
 
+    let options = match Options::from_attributes(&input.attrs) {
+        Ok(options) => options,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     // Check if this is a struct
     let fields = match input.data {
         Data::Struct(data_struct) => data_struct.fields,
@@ -18,25 +122,162 @@ pub fn derive_alloc_zeroed(input: TokenStream) -> TokenStream {
         }
     };
 
-    // Extract field types for the where clause
-    let field_types = fields.iter().map(|field| &field.ty);
+    // The compiler strips `cfg`-disabled fields before derive macros run, so every field seen
+    // here exists in the build and needs its type checked.
+    let field_types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+    if let Some(err) = field_types
+        .iter()
+        .filter_map(|ty| check_field_type(ty))
+        .reduce(|mut errors, err| {
+            errors.combine(err);
+            errors
+        })
+    {
+        return err.to_compile_error().into();
+    }
+
+    // Field types that depend on a type parameter become bounds on the impl. The others are
+    // asserted directly: as where clauses they would only make the impl silently inapplicable
+    // when they mention a lifetime (e.g. `&'a mut [u8]: AllocZeroed`).
+    let type_params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+    let (generic_field_types, concrete_field_types): (Vec<&Type>, Vec<&Type>) = field_types
+        .iter()
+        .partition(|ty| mentions_type_param(ty, &type_params));
+
+    // The trait is named by its absolute path, so the derive works in any module, whether
+    // or not `AllocZeroed` is imported there
+    let krate = quote! { ::alloc_zeroed };
+    let alloc_zeroed = quote! { #krate::AllocZeroed };
 
     // Clone generics before modifying to avoid borrowing issues
     let mut generics = input.generics.clone();
     let where_clause = generics.make_where_clause();
-    for ty in field_types {
+    for ty in &generic_field_types {
         where_clause
             .predicates
-            .push(syn::parse_quote! { #ty: AllocZeroed });
+            .push(syn::parse_quote! { #ty: #alloc_zeroed });
     }
 
     // Now split the original generics (not the modified one)
-    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (impl_generics, ty_generics, original_where_clause) = input.generics.split_for_impl();
+
+    let field_assertions = concrete_field_types.iter().map(|ty| {
+        quote_spanned! {ty.span()=>
+            assert_alloc_zeroed::<#ty>();
+        }
+    });
+
+    // The bytes covered by the fields themselves; the rest of `Self` is padding
+    let fields_size = quote! { (0 #(+ ::core::mem::size_of::<#field_types>())*) };
+
+    // Each field is compared against a zeroed value of its type rather than scanning the
+    // bytes of `self`, which may include padding inside the fields or uninitialized
+    // `MaybeUninit` contents. Requiring `PartialEq` rejects `MaybeUninit` fields.
+    let is_zero = options.is_zero.then(|| {
+        let members = fields.members();
+        let zeroed_field_types = field_types.iter();
+        let comparable_field_types = field_types.iter();
+
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Returns `true` if every field of `self` equals its all-zero value.
+                pub fn is_zero(&self) -> bool
+                where
+                    #(#comparable_field_types: ::core::cmp::PartialEq,)*
+                {
+                    true #(&& {
+                        // SAFETY: Every field type implements `AllocZeroed`, so all-zero is a
+                        // valid value. It is never dropped, as it was not really constructed
+                        let zero = ::core::mem::ManuallyDrop::new(unsafe {
+                            ::core::mem::zeroed::<#zeroed_field_types>()
+                        });
+                        self.#members == *zero
+                    })*
+                }
+            }
+        }
+    });
+
+    let from_bytes = match options.from_bytes {
+        false => None,
+        // An array length cannot depend on generic parameters on stable Rust
+        true if !input.generics.params.is_empty() => {
+            return syn::Error::new_spanned(
+                &input.generics,
+                "`alloc_zeroed(from_bytes)` is not supported on generic types",
+            )
+            .to_compile_error()
+            .into();
+        }
+        // A safe `From` is only sound if every byte pattern is a valid `Self`. The fields
+        // must accept any bytes, which `AllocFilled` guarantees, and the struct must have no
+        // padding, which could not hold arbitrary bytes
+        true => {
+            let filled_assertions = field_types.iter().map(|ty| {
+                quote_spanned! {ty.span()=>
+                    assert_alloc_filled::<#ty>();
+                }
+            });
+
+            Some(quote! {
+                const _: () = {
+                    fn assert_alloc_filled<T: #krate::AllocFilled>() {}
+
+                    fn assert_fields() {
+                        #(#filled_assertions)*
+                    }
+
+                    assert!(
+                        ::core::mem::size_of::<#name>() == #fields_size,
+                        "`alloc_zeroed(from_bytes)` requires a struct without padding"
+                    );
+                };
+
+                impl ::core::convert::From<[u8; ::core::mem::size_of::<#name>()]> for #name {
+                    /// Reinterprets the bytes as a value of this type.
+                    fn from(bytes: [u8; ::core::mem::size_of::<#name>()]) -> Self {
+                        // SAFETY: The array has the size of `Self`, which has no padding and
+                        // only fields that accept any bytes, as asserted above
+                        unsafe { ::core::mem::transmute(bytes) }
+                    }
+                }
+            })
+        }
+    };
+
+    let padding_bytes = options.padding_bytes.then(|| {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// The number of bytes of `Self` not covered by its fields, i.e. padding
+                /// between and after them. Padding inside the fields' own types is not counted.
+                pub const PADDING_BYTES: usize = ::core::mem::size_of::<Self>() - #fields_size;
+            }
+        }
+    });
 
     let expanded = quote! {
         // SAFETY: This macro ensures all fields can be safely zero-initialized
         // by requiring that all field types implement AllocZeroed
-        unsafe impl #impl_generics AllocZeroed for #name #ty_generics #where_clause {}
+        unsafe impl #impl_generics #alloc_zeroed for #name #ty_generics #where_clause {
+            const IS_TRIVIALLY_ZEROABLE: bool = true;
+        }
+
+        const _: () = {
+            fn assert_alloc_zeroed<T: #alloc_zeroed>() {}
+
+            fn assert_fields #impl_generics () #original_where_clause {
+                #(#field_assertions)*
+            }
+        };
+
+        #padding_bytes
+        #is_zero
+        #from_bytes
     };
 
     TokenStream::from(expanded)
@@ -0,0 +1,114 @@
+//! Compile check for `alloc_zeroed` without the `std` feature.
+//!
+//! Building this crate verifies that the buffer API compiles without any allocator, and the
+//! doctests below verify that none of the heap-backed API is exposed. Run with:
+//!
+//! ```text
+//! cargo test --manifest-path no-std-check/Cargo.toml
+//! ```
+//!
+//! ```compile_fail
+//! use alloc_zeroed::AllocZeroedBoxed;
+//! ```
+//!
+//! ```compile_fail
+//! use alloc_zeroed::ZeroBuf;
+//! ```
+//!
+//! ```compile_fail
+//! use alloc_zeroed::HeapStrategy;
+//! ```
+
+#![no_std]
+
+use alloc_zeroed::{AllocError, AllocZeroed, BufferAllocator};
+
+#[derive(AllocZeroed)]
+pub struct Packet {
+    pub header: [u8; 8],
+    pub length: u32,
+    pub payload: [u16; 16],
+}
+
+/// Carves a packet and a checksum table out of `mem` using only the buffer API.
+pub fn carve(mem: &mut [u8]) -> Result<(&mut Packet, &mut [u32]), AllocError> {
+    let mut allocator = BufferAllocator::new(mem);
+    let packet = allocator.alloc::<Packet>()?;
+    let table = allocator.alloc_slice::<u32>(8)?;
+
+    Ok((packet, table))
+}
+
+// The tests use `core` only: this crate is `#![no_std]` and does not import `std`, even
+// though the test harness links it.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc_zeroed::{AllocErrorKind, is_buffer_zeroed};
+
+    #[derive(AllocZeroed)]
+    #[repr(C, align(16))]
+    struct Aligned {
+        lanes: [u32; 4],
+        tag: u8,
+    }
+
+    #[test]
+    fn carves_from_a_stack_buffer() {
+        let mut buffer = [0xFFu8; 256];
+        let (packet, table) = carve(&mut buffer).unwrap();
+
+        assert_eq!(packet.length, 0);
+        assert_eq!(packet.payload, [0; 16]);
+        assert_eq!(table, [0; 8]);
+    }
+
+    #[test]
+    fn zeroes_primitives_and_slices() {
+        let mut buffer = [0xFFu8; 64];
+        let (value, rest) = u64::alloc_zeroed_with_remainder(&mut buffer).unwrap();
+        assert_eq!(*value, 0);
+
+        let slice = u16::alloc_zeroed_slice(rest).unwrap();
+        assert!(!slice.is_empty());
+        assert!(slice.iter().all(|&v| v == 0));
+    }
+
+    #[test]
+    fn aligns_over_aligned_structs() {
+        let mut buffer = [0xFFu8; 128];
+
+        for start in 0..16 {
+            let mem = &mut buffer[start..];
+            let value = Aligned::alloc_zeroed(mem).unwrap();
+
+            assert_eq!(value as *mut Aligned as usize % 16, 0);
+            assert_eq!(value.lanes, [0; 4]);
+            assert_eq!(value.tag, 0);
+            value.tag = 0xFF;
+        }
+    }
+
+    #[test]
+    fn reports_errors_without_allocating() {
+        let mut buffer = [0u8; 4];
+        let err = u64::alloc_zeroed(&mut buffer).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            AllocErrorKind::BufferTooSmall { required: 8, .. }
+        ));
+
+        let mut message = [0u8; 128];
+        assert!(err.write_message(&mut message).is_ok());
+    }
+
+    #[test]
+    fn secure_allocation_clears_the_bytes() {
+        let mut buffer = [0xAAu8; 32];
+        let value = <[u8; 32]>::alloc_zeroed_secure(&mut buffer).unwrap();
+        assert_eq!(*value, [0; 32]);
+        assert!(is_buffer_zeroed(&buffer));
+    }
+}